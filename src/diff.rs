@@ -0,0 +1,238 @@
+//! Line based unified diffs used by the snapshot and golden file matchers.
+
+const CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+// Longest common subsequence diff of two sequences of lines.
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<Line<'a>> {
+    // Lines shared at both ends are matched directly, which keeps small edits to large outputs cheap.
+    let prefix = expected.iter().zip(actual).take_while(|&(old, new)| old == new).count();
+    let suffix = expected[prefix..].iter().rev()
+        .zip(actual[prefix..].iter().rev())
+        .take_while(|&(old, new)| old == new)
+        .count();
+
+    let mut lines: Vec<Line<'a>> = expected[..prefix].iter().map(|line| Line::Same(line)).collect();
+    lcs_diff(&expected[prefix..expected.len() - suffix], &actual[prefix..actual.len() - suffix], &mut lines);
+    lines.extend(expected[expected.len() - suffix..].iter().map(|line| Line::Same(line)));
+    lines
+}
+
+// Hirschberg's algorithm: splits `expected` in half, finds where the halves of a longest common
+// subsequence meet in `actual`, and diffs both sides recursively. Only one row of lengths is kept
+// at a time, so memory stays linear in the input size.
+fn lcs_diff<'a>(expected: &[&'a str], actual: &[&'a str], lines: &mut Vec<Line<'a>>) {
+    match expected.len() {
+        0 => lines.extend(actual.iter().map(|line| Line::Added(line))),
+        1 => match actual.iter().position(|&line| line == expected[0]) {
+            Some(j) => {
+                lines.extend(actual[..j].iter().map(|line| Line::Added(line)));
+                lines.push(Line::Same(expected[0]));
+                lines.extend(actual[j + 1..].iter().map(|line| Line::Added(line)));
+            },
+            None => {
+                lines.push(Line::Removed(expected[0]));
+                lines.extend(actual.iter().map(|line| Line::Added(line)));
+            },
+        },
+        n if actual.is_empty() => lines.extend(expected[..n].iter().map(|line| Line::Removed(line))),
+        n => {
+            let mid = n / 2;
+            let front = lcs_lengths(expected[..mid].iter(), actual.iter());
+            let back = lcs_lengths(expected[mid..].iter().rev(), actual.iter().rev());
+            let m = actual.len();
+            let split = (0..=m).max_by_key(|&j| (front[j] + back[m - j], std::cmp::Reverse(j))).unwrap_or(0);
+            lcs_diff(&expected[..mid], &actual[..split], lines);
+            lcs_diff(&expected[mid..], &actual[split..], lines);
+        },
+    }
+}
+
+// The last row of the longest common subsequence table: entry `j` is the length of the longest
+// common subsequence of all of `expected` and the first `j` lines of `actual`.
+fn lcs_lengths<'a, 'b, E, A>(expected: E, actual: A) -> Vec<usize>
+where E: Iterator<Item=&'b &'a str>, A: Iterator<Item=&'b &'a str> + Clone, 'a: 'b {
+    let m = actual.clone().count();
+    let mut row = vec![0usize; m + 1];
+    for old in expected {
+        // `diagonal` holds the previous row's entry for j - 1.
+        let mut diagonal = 0;
+        for (j, new) in actual.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if old == new { diagonal + 1 } else { above.max(row[j]) };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+fn hunk_range(start: usize, count: usize) -> String {
+    // An empty range names the line before it, as in GNU diff.
+    if count == 0 {
+        format!("{},0", start)
+    } else {
+        format!("{},{}", start + 1, count)
+    }
+}
+
+// Renders a line without its terminator, showing a carriage return as `\r` so that lines differing
+// only in their line ending can be told apart.
+fn render_line(prefix: char, line: &str) -> String {
+    let text = line.strip_suffix('\n').unwrap_or(line);
+    match text.strip_suffix('\r') {
+        Some(text) => format!("{}{}\\r", prefix, text),
+        None => format!("{}{}", prefix, text),
+    }
+}
+
+/// Renders a unified diff from `expected` to `actual` with three lines of context, using the
+/// given labels in the `---` and `+++` header lines.
+///
+/// Lines are compared together with their line endings. Carriage returns are shown as `\r`, and
+/// when only one side ends with a newline, its changed last line is followed by
+/// `\ No newline at end of file`, as in GNU diff.
+pub fn unified_diff(expected: &str, actual: &str, expected_label: &str, actual_label: &str) -> String {
+    let expected_lines: Vec<&str> = expected.split_inclusive('\n').collect();
+    let actual_lines: Vec<&str> = actual.split_inclusive('\n').collect();
+    let lines = diff_lines(&expected_lines, &actual_lines);
+    let mark_missing_newline = expected.ends_with('\n') != actual.ends_with('\n');
+
+    // Line offsets into expected and actual before each diff line.
+    let mut offsets = Vec::with_capacity(lines.len() + 1);
+    let (mut old, mut new) = (0, 0);
+    for line in &lines {
+        offsets.push((old, new));
+        match *line {
+            Line::Same(_) => {
+                old += 1;
+                new += 1;
+            },
+            Line::Removed(_) => old += 1,
+            Line::Added(_) => new += 1,
+        }
+    }
+    offsets.push((old, new));
+
+    let changes: Vec<usize> = lines.iter()
+        .enumerate()
+        .filter(|&(_, line)| !matches!(*line, Line::Same(_)))
+        .map(|(idx, _)| idx)
+        .collect();
+
+    let mut out = vec![format!("--- {}", expected_label), format!("+++ {}", actual_label)];
+    let mut idx = 0;
+    while idx < changes.len() {
+        // Changes closer together than twice the context share a hunk.
+        let mut last = idx;
+        while last + 1 < changes.len() && changes[last + 1] - changes[last] <= 2 * CONTEXT {
+            last += 1;
+        }
+        let start = changes[idx].saturating_sub(CONTEXT);
+        let end = (changes[last] + 1 + CONTEXT).min(lines.len());
+
+        let (old_start, new_start) = offsets[start];
+        let (old_end, new_end) = offsets[end];
+        out.push(format!("@@ -{} +{} @@",
+                         hunk_range(old_start, old_end - old_start),
+                         hunk_range(new_start, new_end - new_start)));
+        for line in &lines[start..end] {
+            let (prefix, text) = match *line {
+                Line::Same(text) => (' ', text),
+                Line::Removed(text) => ('-', text),
+                Line::Added(text) => ('+', text),
+            };
+            out.push(render_line(prefix, text));
+            if mark_missing_newline && prefix != ' ' && !text.ends_with('\n') {
+                out.push(String::from("\\ No newline at end of file"));
+            }
+        }
+        idx = last + 1;
+    }
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff_single_change() {
+        let diff = unified_diff("a\nb\nc\n", "a\nx\nc\n", "old", "new");
+        assert_eq!(diff, "--- old\n+++ new\n@@ -1,3 +1,3 @@\n a\n-b\n+x\n c");
+    }
+
+    #[test]
+    fn test_unified_diff_separate_hunks() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let actual = "one\n2\n3\n4\n5\n6\n7\n8\n9\nten\n";
+        let diff = unified_diff(expected, actual, "old", "new");
+        assert_eq!(diff, "--- old\n+++ new\n\
+                          @@ -1,4 +1,4 @@\n-1\n+one\n 2\n 3\n 4\n\
+                          @@ -7,4 +7,4 @@\n 7\n 8\n 9\n-10\n+ten");
+    }
+
+    #[test]
+    fn test_unified_diff_insertion_into_empty() {
+        let diff = unified_diff("", "a\n", "old", "new");
+        assert_eq!(diff, "--- old\n+++ new\n@@ -0,0 +1,1 @@\n+a");
+    }
+
+    #[test]
+    fn test_unified_diff_line_endings() {
+        let diff = unified_diff("a\nb\n", "a\r\nb\n", "old", "new");
+        assert_eq!(diff, "--- old\n+++ new\n@@ -1,2 +1,2 @@\n-a\n+a\\r\n b");
+    }
+
+    #[test]
+    fn test_unified_diff_missing_newline() {
+        let diff = unified_diff("a\nb\n", "a\nb", "old", "new");
+        assert_eq!(diff, "--- old\n+++ new\n@@ -1,2 +1,2 @@\n a\n-b\n+b\n\\ No newline at end of file");
+
+        let diff = unified_diff("a\nb", "a\nc", "old", "new");
+        assert_eq!(diff, "--- old\n+++ new\n@@ -1,2 +1,2 @@\n a\n-b\n+c");
+    }
+
+    #[test]
+    fn test_diff_lines_trims_common_prefix_and_suffix() {
+        let expected: Vec<String> = (0..100_000).map(|i| i.to_string()).collect();
+        let mut actual = expected.clone();
+        actual[50_000] = String::from("changed");
+        let expected: Vec<&str> = expected.iter().map(String::as_str).collect();
+        let actual: Vec<&str> = actual.iter().map(String::as_str).collect();
+
+        let lines = diff_lines(&expected, &actual);
+        assert_eq!(lines.len(), 100_001);
+        assert_eq!(lines[49_999], Line::Same("49999"));
+        assert_eq!(lines[50_000], Line::Removed("50000"));
+        assert_eq!(lines[50_001], Line::Added("changed"));
+        assert_eq!(lines[50_002], Line::Same("50001"));
+    }
+
+    #[test]
+    fn test_diff_lines_changes_at_both_ends() {
+        let expected: Vec<String> = (0..5_000).map(|i| i.to_string()).collect();
+        let mut actual = expected.clone();
+        actual[0] = String::from("first");
+        actual[4_999] = String::from("last");
+        actual.remove(2_500);
+        let expected: Vec<&str> = expected.iter().map(String::as_str).collect();
+        let actual: Vec<&str> = actual.iter().map(String::as_str).collect();
+
+        let lines = diff_lines(&expected, &actual);
+        let changed: Vec<&Line> = lines.iter().filter(|line| !matches!(**line, Line::Same(_))).collect();
+        assert_eq!(changed, vec![&Line::Removed("0"), &Line::Added("first"), &Line::Removed("2500"),
+                                 &Line::Removed("4999"), &Line::Added("last")]);
+        assert_eq!(lines.len(), 5_002);
+    }
+
+    #[test]
+    fn test_unified_diff_equal() {
+        assert_eq!(unified_diff("a\n", "a\n", "old", "new"), "--- old\n+++ new");
+    }
+}
@@ -1,6 +1,8 @@
 use dsl::{Assertion, Matcher};
+use dsl::matchers::PanicOutcome;
 use time::{Duration, PreciseTime};
 use std::thread;
+use std::time::Duration as StdDuration;
 
 pub struct Expect<'a, A: 'a> {
     actual: &'a A,
@@ -9,7 +11,7 @@ pub struct Expect<'a, A: 'a> {
 impl<'a, A> Expect<'a, A> {
     pub fn new(actual: &'a A) -> Expect<'a, A> {
         Expect{
-            actual: actual,
+            actual,
         }
     }
 }
@@ -17,17 +19,39 @@ impl<'a, A> Expect<'a, A> {
 impl<'a, A: 'a> Assertion<A> for Expect<'a, A> {
     fn to<M: Matcher<A>>(self, matcher: M) {
         if !matcher.matches(self.actual) {
-            panic!(matcher.failure_message(self.actual));
+            panic!("{}", matcher.failure_message(self.actual));
         }
     }
 
     fn not_to<M: Matcher<A>>(self, matcher: M) {
         if matcher.matches(self.actual) {
-            panic!(matcher.negated_failure_message(self.actual));
+            panic!("{}", matcher.negated_failure_message(self.actual));
         }
     }
 }
 
+pub struct ExpectFn {
+    outcome: PanicOutcome,
+}
+
+impl ExpectFn {
+    pub fn new<F, R>(f: F) -> ExpectFn where F: FnOnce() -> R {
+        ExpectFn {
+            outcome: PanicOutcome::catch(f),
+        }
+    }
+}
+
+impl Assertion<PanicOutcome> for ExpectFn {
+    fn to<M: Matcher<PanicOutcome>>(self, matcher: M) {
+        Expect::new(&self.outcome).to(matcher);
+    }
+
+    fn not_to<M: Matcher<PanicOutcome>>(self, matcher: M) {
+        Expect::new(&self.outcome).not_to(matcher);
+    }
+}
+
 #[derive(Clone, Debug, Copy)]
 pub enum AsyncType {
     Eventual,
@@ -35,7 +59,7 @@ pub enum AsyncType {
 }
 
 pub struct Async<A> {
-    func: Box<Fn() -> A>,
+    func: Box<dyn Fn() -> A>,
     timeout: Duration,
     polling_interval: u32,
     async_type: AsyncType
@@ -46,9 +70,9 @@ impl<A> Async<A> {
         where F: 'static + Fn() -> A {
             Async {
                 func: Box::new(f),
-                timeout: timeout,
+                timeout,
                 polling_interval: 10,
-                async_type: async_type,
+                async_type,
             }
         }
 
@@ -56,17 +80,13 @@ impl<A> Async<A> {
     fn check_match<M: Matcher<A>>(&self, matcher: &M, actual: A) -> bool {
         match self.async_type {
             AsyncType::Eventual => {
-                if matcher.matches(&actual) {
-                    true
-                } else {
-                    false
-                }
+                matcher.matches(&actual)
             },
             AsyncType::Consistent => {
                 if matcher.matches(&actual) {
                     false
                 } else {
-                    panic!(matcher.failure_message(&actual));
+                    panic!("{}", matcher.failure_message(&actual));
                 }
             },
         }
@@ -76,17 +96,13 @@ impl<A> Async<A> {
     fn check_negated_match<M: Matcher<A>>(&self, matcher: &M, actual: A) -> bool {
         match self.async_type {
             AsyncType::Eventual => {
-                if !matcher.matches(&actual) {
-                    true
-                } else {
-                    false
-                }
+                !matcher.matches(&actual)
             },
             AsyncType::Consistent => {
                 if !matcher.matches(&actual) {
                     false
                 } else {
-                    panic!(matcher.negated_failure_message(&actual));
+                    panic!("{}", matcher.negated_failure_message(&actual));
                 }
             },
         }
@@ -95,7 +111,7 @@ impl<A> Async<A> {
     fn check_at_end<M: Matcher<A>>(&self, matcher: &M, actual: A) {
         match self.async_type {
             AsyncType::Eventual => {
-                panic!(matcher.failure_message(&actual));
+                panic!("{}", matcher.failure_message(&actual));
             },
             AsyncType::Consistent => {},
         }
@@ -104,7 +120,7 @@ impl<A> Async<A> {
     fn check_negated_at_end<M: Matcher<A>>(&self, matcher: &M, actual: A) {
         match self.async_type {
             AsyncType::Eventual => {
-                panic!(matcher.negated_failure_message(&actual));
+                panic!("{}", matcher.negated_failure_message(&actual));
             },
             AsyncType::Consistent => {},
         }
@@ -113,14 +129,14 @@ impl<A> Async<A> {
 
 impl<A> Assertion<A> for Async<A> {
     fn to<M: Matcher<A>>(self, matcher: M) {
-        let ref f = self.func;
+        let f = &self.func;
         let start = PreciseTime::now();
         while start.to(PreciseTime::now()) < self.timeout {
             let actual = f();
             if self.check_match(&matcher, actual) {
                 return
             }
-            thread::sleep_ms(self.polling_interval);
+            thread::sleep(StdDuration::from_millis(u64::from(self.polling_interval)));
         }
 
         let actual = f();
@@ -128,14 +144,14 @@ impl<A> Assertion<A> for Async<A> {
     }
 
     fn not_to<M: Matcher<A>>(self, matcher: M) {
-        let ref f = self.func;
+        let f = &self.func;
         let start = PreciseTime::now();
         while start.to(PreciseTime::now()) < self.timeout {
             let actual = f();
             if self.check_negated_match(&matcher, actual) {
                 return
             }
-            thread::sleep_ms(self.polling_interval);
+            thread::sleep(StdDuration::from_millis(u64::from(self.polling_interval)));
         }
 
         let actual = f();
@@ -150,6 +166,7 @@ mod tests {
     use std::sync::{Arc};
     use std::sync::atomic::{AtomicBool, Ordering};
     use std::thread;
+    use std::time::Duration as StdDuration;
     use dsl::*;
 
     #[derive(Debug, PartialEq)]
@@ -169,7 +186,7 @@ mod tests {
         });
 
         let handle = thread::spawn(move || {
-            thread::sleep_ms(500);
+            thread::sleep(StdDuration::from_millis(500));
             change.store(true, Ordering::SeqCst);
         });
 
@@ -202,7 +219,7 @@ mod tests {
         });
 
         let handle = thread::spawn(move || {
-            thread::sleep_ms(500);
+            thread::sleep(StdDuration::from_millis(500));
             change.store(true, Ordering::SeqCst);
         });
 
@@ -246,7 +263,7 @@ mod tests {
         });
 
         let handle = thread::spawn(move || {
-            thread::sleep_ms(500);
+            thread::sleep(StdDuration::from_millis(500));
             change.store(false, Ordering::SeqCst);
         });
 
@@ -279,7 +296,7 @@ mod tests {
         });
 
         let handle = thread::spawn(move || {
-            thread::sleep_ms(500);
+            thread::sleep(StdDuration::from_millis(500));
             change.store(true, Ordering::SeqCst);
         });
 
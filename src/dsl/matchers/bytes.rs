@@ -0,0 +1,264 @@
+use std::borrow::Cow;
+use dsl::Matcher;
+
+const ROW_WIDTH: usize = 16;
+const MAX_LISTED: usize = 16;
+const MAX_ROWS: usize = 8;
+const MAX_RENDERED: usize = 64;
+
+/// Parses a string of hex digits, ignoring whitespace, into bytes.
+pub fn parse_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<char> = hex.chars().filter(|c| !c.is_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err(format!("odd number of hex digits in {:?}", hex))
+    }
+
+    digits.chunks(2).map(|pair| {
+        let byte: String = pair.iter().collect();
+        // Checked up front, since `from_str_radix` also accepts a leading `+`.
+        if !pair.iter().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("invalid hex byte {:?} in {:?}", byte, hex))
+        }
+        u8::from_str_radix(&byte, 16).map_err(|_| format!("invalid hex byte {:?} in {:?}", byte, hex))
+    }).collect()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let mut rendered: Vec<String> = bytes.iter()
+        .take(MAX_RENDERED)
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    if bytes.len() > MAX_RENDERED {
+        rendered.push(format!("... ({} bytes)", bytes.len()));
+    }
+    rendered.join(" ")
+}
+
+fn ascii(byte: u8) -> char {
+    if (0x20..0x7f).contains(&byte) {
+        byte as char
+    } else {
+        '.'
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0)
+    }
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+fn differing_offsets(expected: &[u8], actual: &[u8]) -> Vec<usize> {
+    let len = expected.len().max(actual.len());
+    (0..len).filter(|&i| expected.get(i) != actual.get(i)).collect()
+}
+
+// Renders one side of a dump row: each byte takes four columns so that differing bytes can be
+// bracketed without breaking the alignment, followed by the ASCII rendering.
+fn dump_side(bytes: &[u8], row: usize, differing: &[usize]) -> String {
+    let mut cells = String::new();
+    let mut text = String::new();
+    for offset in row..row + ROW_WIDTH {
+        match bytes.get(offset) {
+            Some(&byte) => {
+                if differing.binary_search(&offset).is_ok() {
+                    cells.push_str(&format!("[{:02x}]", byte));
+                } else {
+                    cells.push_str(&format!(" {:02x} ", byte));
+                }
+                text.push(ascii(byte));
+            },
+            None => {
+                cells.push_str("    ");
+                text.push(' ');
+            },
+        }
+    }
+    format!("{}|{}|", cells, text)
+}
+
+// Side-by-side hex dump of the first rows containing differences, with differing bytes
+// bracketed. `differing` must be sorted.
+fn hex_dump(expected: &[u8], actual: &[u8], differing: &[usize]) -> String {
+    let mut rows: Vec<usize> = differing.iter().map(|offset| offset - offset % ROW_WIDTH).collect();
+    rows.dedup();
+
+    let width = ROW_WIDTH * 4 + ROW_WIDTH + 2;
+    let mut lines = vec![format!("{:8}  {:width$}  {}", "offset", "expected", "actual", width = width)];
+    for &row in rows.iter().take(MAX_ROWS) {
+        lines.push(format!("{:08x}  {}  {}",
+                           row, dump_side(expected, row, differing), dump_side(actual, row, differing)));
+    }
+    if rows.len() > MAX_ROWS {
+        lines.push(format!("... and {} more rows with differences", rows.len() - MAX_ROWS));
+    }
+    lines.join("\n")
+}
+
+#[derive(Debug)]
+pub enum BytesMatcher<'a> {
+    Equal(Cow<'a, [u8]>),
+    StartWith(&'a [u8]),
+    Contain(&'a [u8]),
+}
+
+impl<'a> BytesMatcher<'a> {
+    fn matches_bytes(&self, actual: &[u8]) -> bool {
+        match *self {
+            BytesMatcher::Equal(ref expected) => &**expected == actual,
+            BytesMatcher::StartWith(expected) => actual.starts_with(expected),
+            BytesMatcher::Contain(expected) => find(actual, expected).is_some(),
+        }
+    }
+}
+
+impl<'a, A: AsRef<[u8]>> Matcher<A> for BytesMatcher<'a> {
+    fn matches(&self, actual: &A) -> bool {
+        self.matches_bytes(actual.as_ref())
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let actual = actual.as_ref();
+        match *self {
+            BytesMatcher::Equal(ref expected) => {
+                let differing = differing_offsets(expected, actual);
+                let mut listed: Vec<String> = differing.iter()
+                    .take(MAX_LISTED)
+                    .map(|offset| format!("{:#x}", offset))
+                    .collect();
+                if differing.len() > MAX_LISTED {
+                    listed.push(String::from("..."));
+                }
+                format!("expected bytes to be equal, found {} differing bytes \
+                         (expected length {}, actual length {}) at offsets [{}]:\n{}",
+                        differing.len(), expected.len(), actual.len(), listed.join(", "),
+                        hex_dump(expected, actual, &differing))
+            },
+            BytesMatcher::StartWith(expected) => {
+                let prefix = &actual[..expected.len().min(actual.len())];
+                format!("expected bytes to start with [{}], found [{}]", hex(expected), hex(prefix))
+            },
+            BytesMatcher::Contain(expected) => {
+                format!("expected to find bytes [{}] in [{}]", hex(expected), hex(actual))
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let actual = actual.as_ref();
+        match *self {
+            BytesMatcher::Equal(ref expected) => {
+                format!("expected bytes not to equal [{}]", hex(expected))
+            },
+            BytesMatcher::StartWith(expected) => {
+                format!("expected bytes not to start with [{}]", hex(expected))
+            },
+            BytesMatcher::Contain(expected) => {
+                format!("expected not to find bytes [{}], found at offset {:#x}",
+                        hex(expected), find(actual, expected).unwrap_or(0))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use dsl::Matcher;
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("de ad BE\nef"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(parse_hex("dea"), Err(String::from("odd number of hex digits in \"dea\"")));
+        assert_eq!(parse_hex("zz"), Err(String::from("invalid hex byte \"zz\" in \"zz\"")));
+        assert_eq!(parse_hex("+f"), Err(String::from("invalid hex byte \"+f\" in \"+f\"")));
+    }
+
+    #[test]
+    fn test_equal_matches() {
+        let expected = [1u8, 2, 3];
+        let test = BytesMatcher::Equal(Cow::Borrowed(&expected));
+        assert!(test.matches(&vec![1u8, 2, 3]));
+        assert!(test.matches(&[1u8, 2, 3]));
+        assert!(test.matches(&&expected[..]));
+        assert!(!test.matches(&vec![1u8, 2]));
+    }
+
+    #[test]
+    fn test_equal_failure_msg() {
+        let expected = b"hello, world!!!!ab";
+        let test = BytesMatcher::Equal(Cow::Borrowed(expected));
+        let msg = test.failure_message(&b"hello, World!!!!a".to_vec());
+        let lines: Vec<&str> = msg.lines().collect();
+        assert_eq!(lines[0], "expected bytes to be equal, found 2 differing bytes \
+                              (expected length 18, actual length 17) at offsets [0x7, 0x11]:");
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("offset    expected"));
+        assert_eq!(lines[2], "00000000   68  65  6c  6c  6f  2c  20 [77] 6f  72  6c  64  21  21  21  21 \
+                              |hello, world!!!!|   68  65  6c  6c  6f  2c  20 [57] 6f  72  6c  64  21  21  21  21 \
+                              |hello, World!!!!|");
+        assert_eq!(lines[3], format!("00000010   61 [62]{:56}|ab{:14}|   61 {:60}|a{:15}|", "", "", "", ""));
+    }
+
+    #[test]
+    fn test_equal_large_failure_msg() {
+        let expected = vec![0xaau8; 200 * 1024];
+        let test = BytesMatcher::Equal(Cow::Borrowed(&expected));
+        let msg = test.failure_message(&Vec::<u8>::new());
+        let lines: Vec<&str> = msg.lines().collect();
+        assert!(lines[0].starts_with("expected bytes to be equal, found 204800 differing bytes \
+                                      (expected length 204800, actual length 0) at offsets [0x0, 0x1,"));
+        assert_eq!(lines.len(), 2 + MAX_ROWS + 1);
+        assert_eq!(lines[2 + MAX_ROWS - 1].get(..8), Some("00000070"));
+        assert_eq!(lines[2 + MAX_ROWS], "... and 12792 more rows with differences");
+    }
+
+    #[test]
+    fn test_equal_negated_failure_msg() {
+        let test = BytesMatcher::Equal(Cow::Owned(vec![0xde, 0xad]));
+        let msg = test.negated_failure_message(&vec![0xdeu8, 0xad]);
+        assert_eq!(msg, String::from("expected bytes not to equal [de ad]"));
+    }
+
+    #[test]
+    fn test_start_with_matches() {
+        let expected = [0xdeu8, 0xad];
+        let test = BytesMatcher::StartWith(&expected);
+        assert!(test.matches(&vec![0xdeu8, 0xad, 0xbe]));
+        assert!(!test.matches(&vec![0xdeu8]));
+    }
+
+    #[test]
+    fn test_start_with_failure_msg() {
+        let expected = [0xdeu8, 0xad];
+        let test = BytesMatcher::StartWith(&expected);
+        let msg = test.failure_message(&vec![0xdeu8, 0xae, 0xbe]);
+        assert_eq!(msg, String::from("expected bytes to start with [de ad], found [de ae]"));
+    }
+
+    #[test]
+    fn test_contain_matches() {
+        let expected = [0xadu8, 0xbe];
+        let test = BytesMatcher::Contain(&expected);
+        assert!(test.matches(&[0xdeu8, 0xad, 0xbe, 0xef]));
+        assert!(!test.matches(&[0xdeu8, 0xad]));
+    }
+
+    #[test]
+    fn test_contain_failure_msg() {
+        let expected = [0xbeu8];
+        let test = BytesMatcher::Contain(&expected);
+        let msg = test.failure_message(&[0xdeu8, 0xad]);
+        assert_eq!(msg, String::from("expected to find bytes [be] in [de ad]"));
+    }
+
+    #[test]
+    fn test_contain_negated_failure_msg() {
+        let expected = [0xbeu8];
+        let test = BytesMatcher::Contain(&expected);
+        let msg = test.negated_failure_message(&[0xdeu8, 0xad, 0xbe]);
+        assert_eq!(msg, String::from("expected not to find bytes [be], found at offset 0x2"));
+    }
+}
@@ -0,0 +1,284 @@
+use dsl::Matcher;
+
+const MAX_LISTED: usize = 5;
+
+/// One- or two-dimensional float data that can be compared element-wise.
+pub trait FloatData {
+    /// Whether the data is two-dimensional, which changes how indices are shown.
+    const NESTED: bool;
+
+    /// The rows of the data. One-dimensional data is a single row.
+    fn rows(&self) -> Vec<&[f64]>;
+}
+
+impl FloatData for [f64] {
+    const NESTED: bool = false;
+
+    fn rows(&self) -> Vec<&[f64]> {
+        vec![self]
+    }
+}
+
+impl FloatData for Vec<f64> {
+    const NESTED: bool = false;
+
+    fn rows(&self) -> Vec<&[f64]> {
+        vec![self]
+    }
+}
+
+impl<const N: usize> FloatData for [f64; N] {
+    const NESTED: bool = false;
+
+    fn rows(&self) -> Vec<&[f64]> {
+        vec![self]
+    }
+}
+
+impl FloatData for [Vec<f64>] {
+    const NESTED: bool = true;
+
+    fn rows(&self) -> Vec<&[f64]> {
+        self.iter().map(|row| &row[..]).collect()
+    }
+}
+
+impl FloatData for Vec<Vec<f64>> {
+    const NESTED: bool = true;
+
+    fn rows(&self) -> Vec<&[f64]> {
+        self.iter().map(|row| &row[..]).collect()
+    }
+}
+
+impl<const M: usize, const N: usize> FloatData for [[f64; M]; N] {
+    const NESTED: bool = true;
+
+    fn rows(&self) -> Vec<&[f64]> {
+        self.iter().map(|row| &row[..]).collect()
+    }
+}
+
+// Where the shapes of the expected and actual data differ, if they do.
+fn shape_mismatch(expected: &[&[f64]], actual: &[&[f64]], expected_nested: bool, nested: bool) -> Option<String> {
+    if expected_nested != nested {
+        let dimensions = |nested| if nested { "two-dimensional" } else { "one-dimensional" };
+        return Some(format!("expected {} data, found {} data", dimensions(expected_nested), dimensions(nested)))
+    }
+    if !nested {
+        let (expected, actual) = (expected[0].len(), actual[0].len());
+        if expected != actual {
+            return Some(format!("expected {} elements, found {}", expected, actual))
+        }
+        return None
+    }
+
+    if expected.len() != actual.len() {
+        return Some(format!("expected {} rows, found {}", expected.len(), actual.len()))
+    }
+    expected.iter().zip(actual).enumerate()
+        .find(|&(_, (e, a))| e.len() != a.len())
+        .map(|(row, (e, a))| format!("expected row {} to have {} columns, found {}", row, e.len(), a.len()))
+}
+
+struct Mismatch {
+    index: String,
+    expected: f64,
+    actual: f64,
+    absolute: f64,
+    // None when the expected value is zero, where a relative error is undefined.
+    relative: Option<f64>,
+}
+
+fn mismatches(expected: &[&[f64]], actual: &[&[f64]], nested: bool, tolerance: f64) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    for (row, (expected_row, actual_row)) in expected.iter().zip(actual).enumerate() {
+        for (column, (&e, &a)) in expected_row.iter().zip(actual_row.iter()).enumerate() {
+            let absolute = (a - e).abs();
+            // Equal infinities have a NaN difference, so check for exact equality first. NaN on
+            // either side makes both comparisons false, counting as a mismatch.
+            let within = a == e || absolute <= tolerance;
+            if !within {
+                mismatches.push(Mismatch {
+                    index: if nested { format!("[{}][{}]", row, column) } else { format!("[{}]", column) },
+                    expected: e,
+                    actual: a,
+                    absolute,
+                    relative: if e == 0.0 { None } else { Some(absolute / e.abs()) },
+                });
+            }
+        }
+    }
+    mismatches
+}
+
+fn worst<F: Fn(&Mismatch) -> Option<f64>>(mismatches: &[Mismatch], error: F) -> String {
+    // The first of the largest errors, falling back to a NaN error when there is nothing else.
+    let worst = mismatches.iter()
+        .filter_map(|m| error(m).map(|e| (e, m)))
+        .filter(|&(e, _)| !e.is_nan())
+        .fold(None, |worst: Option<(f64, &Mismatch)>, (e, m)| match worst {
+            Some((w, _)) if w >= e => worst,
+            _ => Some((e, m)),
+        })
+        .or_else(|| mismatches.iter().filter_map(|m| error(m).map(|e| (e, m))).next());
+    match worst {
+        Some((e, m)) => format!("{:?} at {}", e, m.index),
+        None if mismatches.is_empty() => String::from("none"),
+        None => String::from("n/a"),
+    }
+}
+
+#[derive(Debug)]
+pub struct CloseToAll<'a, E: 'a + ?Sized> {
+    expected: &'a E,
+    tolerance: f64,
+}
+
+impl<'a, E: ?Sized> CloseToAll<'a, E> {
+    pub fn new(expected: &'a E, tolerance: f64) -> CloseToAll<'a, E> {
+        CloseToAll {
+            expected,
+            tolerance,
+        }
+    }
+}
+
+impl<'a, A: FloatData, E: FloatData + ?Sized> Matcher<A> for CloseToAll<'a, E> {
+    fn matches(&self, actual: &A) -> bool {
+        let (expected, actual) = (self.expected.rows(), actual.rows());
+        shape_mismatch(&expected, &actual, E::NESTED, A::NESTED).is_none() &&
+            mismatches(&expected, &actual, A::NESTED, self.tolerance).is_empty()
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let (expected, actual) = (self.expected.rows(), actual.rows());
+        if let Some(mismatch) = shape_mismatch(&expected, &actual, E::NESTED, A::NESTED) {
+            return format!("expected values to be within {:?} of the expected values, but the shapes differ: {}",
+                           self.tolerance, mismatch)
+        }
+
+        let mismatches = mismatches(&expected, &actual, A::NESTED, self.tolerance);
+        let total: usize = expected.iter().map(|row| row.len()).sum();
+        let mut listed: Vec<String> = mismatches.iter()
+            .take(MAX_LISTED)
+            .map(|m| format!("\n  {}: expected {:?}, found {:?}", m.index, m.expected, m.actual))
+            .collect();
+        if mismatches.len() > MAX_LISTED {
+            listed.push(format!("\n  ... and {} more", mismatches.len() - MAX_LISTED));
+        }
+        format!("expected values to be within {:?} of the expected values, found {} mismatches out of {} \
+                 (worst absolute error {}, worst relative error {}):{}",
+                self.tolerance, mismatches.len(), total,
+                worst(&mismatches, |m| Some(m.absolute)), worst(&mismatches, |m| m.relative), listed.concat())
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let total: usize = actual.rows().iter().map(|row| row.len()).sum();
+        format!("expected values not to all be within {:?} of the expected values, but all {} were",
+                self.tolerance, total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use super::*;
+
+    #[test]
+    fn test_close_to_all_matches() {
+        let expected = [1.0, 2.0, 3.0];
+        let test = CloseToAll::new(&expected, 1e-6);
+        assert!(test.matches(&vec![1.0, 2.0000001, 2.9999999]));
+        assert!(test.matches(&[1.0, 2.0, 3.0]));
+        assert!(!test.matches(&vec![1.0, 2.1, 3.0]));
+        assert!(!test.matches(&vec![1.0, 2.0]));
+        assert!(!test.matches(&vec![1.0, f64::NAN, 3.0]));
+
+        let expected = [f64::INFINITY, f64::NEG_INFINITY];
+        let test = CloseToAll::new(&expected, 1e-6);
+        assert!(test.matches(&[f64::INFINITY, f64::NEG_INFINITY]));
+        assert!(!test.matches(&[f64::NEG_INFINITY, f64::INFINITY]));
+    }
+
+    #[test]
+    fn test_close_to_all_nested_matches() {
+        let expected = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        let test = CloseToAll::new(&expected, 0.01);
+        assert!(test.matches(&vec![vec![1.001, 2.0], vec![3.0, 3.999]]));
+        assert!(test.matches(&[[1.0, 2.0], [3.0, 4.0]]));
+        assert!(!test.matches(&vec![vec![1.0, 2.0], vec![3.0, 4.5]]));
+        assert!(!test.matches(&vec![vec![1.0, 2.0], vec![3.0]]));
+    }
+
+    #[test]
+    fn test_close_to_all_failure_msg() {
+        let expected = [1.0, 2.0, 4.0, 8.0];
+        let test = CloseToAll::new(&expected[..], 0.01);
+        let msg = test.failure_message(&vec![1.0, 2.5, 4.0, 9.0]);
+        assert_eq!(msg, String::from("expected values to be within 0.01 of the expected values, \
+                                      found 2 mismatches out of 4 \
+                                      (worst absolute error 1.0 at [3], worst relative error 0.25 at [1]):\n  \
+                                      [1]: expected 2.0, found 2.5\n  \
+                                      [3]: expected 8.0, found 9.0"));
+    }
+
+    #[test]
+    fn test_close_to_all_nested_failure_msg() {
+        let expected = vec![vec![0.0; 3]; 3];
+        let test = CloseToAll::new(&expected, 0.1);
+        let msg = test.failure_message(&vec![vec![1.0; 3]; 3]);
+        assert_eq!(msg, String::from("expected values to be within 0.1 of the expected values, \
+                                      found 9 mismatches out of 9 \
+                                      (worst absolute error 1.0 at [0][0], worst relative error n/a):\n  \
+                                      [0][0]: expected 0.0, found 1.0\n  \
+                                      [0][1]: expected 0.0, found 1.0\n  \
+                                      [0][2]: expected 0.0, found 1.0\n  \
+                                      [1][0]: expected 0.0, found 1.0\n  \
+                                      [1][1]: expected 0.0, found 1.0\n  \
+                                      ... and 4 more"));
+    }
+
+    #[test]
+    fn test_close_to_all_mixed_zero_failure_msg() {
+        let expected = [0.0, 2.0];
+        let test = CloseToAll::new(&expected, 0.1);
+        let msg = test.failure_message(&vec![1.0, 3.0]);
+        assert_eq!(msg, String::from("expected values to be within 0.1 of the expected values, \
+                                      found 2 mismatches out of 2 \
+                                      (worst absolute error 1.0 at [0], worst relative error 0.5 at [1]):\n  \
+                                      [0]: expected 0.0, found 1.0\n  \
+                                      [1]: expected 2.0, found 3.0"));
+    }
+
+    #[test]
+    fn test_close_to_all_shape_failure_msg() {
+        let expected = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        let test = CloseToAll::new(&expected, 0.1);
+        let msg = test.failure_message(&vec![vec![1.0, 2.0], vec![3.0]]);
+        assert_eq!(msg, String::from("expected values to be within 0.1 of the expected values, \
+                                      but the shapes differ: expected row 1 to have 2 columns, found 1"));
+
+        let msg = test.failure_message(&vec![vec![1.0, 2.0]]);
+        assert_eq!(msg, String::from("expected values to be within 0.1 of the expected values, \
+                                      but the shapes differ: expected 2 rows, found 1"));
+
+        let expected = [1.0, 2.0];
+        let msg = CloseToAll::new(&expected, 0.1).failure_message(&vec![1.0]);
+        assert_eq!(msg, String::from("expected values to be within 0.1 of the expected values, \
+                                      but the shapes differ: expected 2 elements, found 1"));
+
+        let msg = CloseToAll::new(&expected, 0.1).failure_message(&vec![vec![1.0, 2.0]]);
+        assert_eq!(msg, String::from("expected values to be within 0.1 of the expected values, \
+                                      but the shapes differ: expected one-dimensional data, found two-dimensional data"));
+    }
+
+    #[test]
+    fn test_close_to_all_negated_failure_msg() {
+        let expected = [1.0, 2.0];
+        let test = CloseToAll::new(&expected, 0.1);
+        let msg = test.negated_failure_message(&vec![1.0, 2.0]);
+        assert_eq!(msg, String::from("expected values not to all be within 0.1 of the expected values, \
+                                      but all 2 were"));
+    }
+}
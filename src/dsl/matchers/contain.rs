@@ -6,9 +6,9 @@ pub struct Contain<'a, E: 'a> {
 }
 
 impl<'a, E> Contain<'a, E> {
-    pub fn new(expected: &'a E) -> Contain<E> {
+    pub fn new(expected: &'a E) -> Contain<'a, E> {
         Contain{
-            expected: expected,
+            expected,
         }
     }
 }
@@ -17,7 +17,7 @@ impl<'c, E: Debug + PartialEq<T>, I, T> Matcher<I> for Contain<'c, E>
 where for<'a> &'a I: IntoIterator<Item=&'a T> {
     fn matches(&self, actual: &I) -> bool {
         for a in actual {
-            if self.expected.eq(&a) {
+            if self.expected.eq(a) {
                 return true
             }
         }
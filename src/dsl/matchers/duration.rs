@@ -0,0 +1,313 @@
+use std::cmp::Ordering;
+use std::time::{Duration, Instant, SystemTime};
+use dsl::Matcher;
+
+// Formats a value with up to three decimals, dropping trailing zeros.
+fn decimal(value: f64) -> String {
+    let formatted = format!("{:.3}", value);
+    formatted.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
+
+/// Renders a duration for humans, e.g. `250ms`, `1.5s` or `2m 5s`.
+pub fn human_duration(duration: Duration) -> String {
+    // Each unit shows up to three decimals, so round to that precision before picking the unit,
+    // otherwise e.g. 59.9999s would be shown as "60s" and 999.9999ms as "1000ms".
+    let nanos = duration.as_nanos();
+    let micros = (nanos + 500) / 1_000;
+    let millis = (nanos + 500_000) / 1_000_000;
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{}µs", decimal(nanos as f64 / 1e3))
+    } else if micros < 1_000_000 {
+        format!("{}ms", decimal(micros as f64 / 1e3))
+    } else if millis < 60_000 {
+        format!("{}s", decimal(millis as f64 / 1e3))
+    } else {
+        let (secs, millis) = (millis / 1000, millis % 1000);
+        let mut parts = Vec::new();
+        if secs >= 3600 {
+            parts.push(format!("{}h", secs / 3600));
+        }
+        if secs % 3600 >= 60 {
+            parts.push(format!("{}m", secs % 3600 / 60));
+        }
+        if secs % 60 > 0 || millis > 0 {
+            parts.push(format!("{}s", decimal((secs % 60) as f64 + millis as f64 / 1e3)));
+        }
+        parts.join(" ")
+    }
+}
+
+/// A point in time that can be compared with the current time.
+pub trait Timestamp: Copy {
+    /// The current time.
+    fn now() -> Self;
+
+    /// How far this time is from another, and on which side of it.
+    fn offset_from(&self, other: &Self) -> (Duration, Ordering);
+}
+
+impl Timestamp for SystemTime {
+    fn now() -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn offset_from(&self, other: &SystemTime) -> (Duration, Ordering) {
+        match self.duration_since(*other) {
+            Ok(offset) if offset == Duration::from_secs(0) => (offset, Ordering::Equal),
+            Ok(offset) => (offset, Ordering::Greater),
+            Err(err) => (err.duration(), Ordering::Less),
+        }
+    }
+}
+
+impl Timestamp for Instant {
+    fn now() -> Instant {
+        Instant::now()
+    }
+
+    fn offset_from(&self, other: &Instant) -> (Duration, Ordering) {
+        match self.cmp(other) {
+            Ordering::Less => (other.duration_since(*self), Ordering::Less),
+            ordering => (self.duration_since(*other), ordering),
+        }
+    }
+}
+
+// Where a time lies relative to a reference, e.g. "1.5s after now".
+fn relation((offset, ordering): (Duration, Ordering), reference: &str) -> String {
+    match ordering {
+        Ordering::Less => format!("{} before {}", human_duration(offset), reference),
+        Ordering::Greater => format!("{} after {}", human_duration(offset), reference),
+        Ordering::Equal => format!("equal to {}", reference),
+    }
+}
+
+#[derive(Debug)]
+pub enum DurationMatcher {
+    ShorterThan(Duration),
+    LongerThan(Duration),
+    Within(Duration, Duration),
+}
+
+impl DurationMatcher {
+    fn expectation(&self) -> String {
+        match *self {
+            DurationMatcher::ShorterThan(limit) => format!("be shorter than {}", human_duration(limit)),
+            DurationMatcher::LongerThan(limit) => format!("be longer than {}", human_duration(limit)),
+            DurationMatcher::Within(tolerance, expected) => {
+                format!("be within {} of {}", human_duration(tolerance), human_duration(expected))
+            },
+        }
+    }
+
+    fn explanation(&self, actual: Duration) -> String {
+        match *self {
+            DurationMatcher::Within(_, expected) => {
+                format!(", but it differed by {}", human_duration(actual.abs_diff(expected)))
+            },
+            _ => String::new(),
+        }
+    }
+}
+
+impl Matcher<Duration> for DurationMatcher {
+    fn matches(&self, actual: &Duration) -> bool {
+        match *self {
+            DurationMatcher::ShorterThan(limit) => *actual < limit,
+            DurationMatcher::LongerThan(limit) => *actual > limit,
+            DurationMatcher::Within(tolerance, expected) => actual.abs_diff(expected) <= tolerance,
+        }
+    }
+
+    fn failure_message(&self, actual: &Duration) -> String {
+        format!("expected duration {} to {}{}",
+                human_duration(*actual), self.expectation(), self.explanation(*actual))
+    }
+
+    fn negated_failure_message(&self, actual: &Duration) -> String {
+        format!("expected duration {} not to {}{}",
+                human_duration(*actual), self.expectation(), self.explanation(*actual))
+    }
+}
+
+#[derive(Debug)]
+pub enum TimeMatcher<T> {
+    Before(T),
+    After(T),
+    WithinOfNow(Duration),
+}
+
+impl<T: Timestamp> TimeMatcher<T> {
+    fn expectation(&self) -> String {
+        match *self {
+            TimeMatcher::Before(_) => String::from("be before the given time"),
+            TimeMatcher::After(_) => String::from("be after the given time"),
+            TimeMatcher::WithinOfNow(tolerance) => format!("be within {} of now", human_duration(tolerance)),
+        }
+    }
+
+    fn found(&self, actual: &T) -> String {
+        match *self {
+            TimeMatcher::Before(ref expected) | TimeMatcher::After(ref expected) => {
+                relation(actual.offset_from(expected), "the given time")
+            },
+            TimeMatcher::WithinOfNow(_) => relation(actual.offset_from(&T::now()), "now"),
+        }
+    }
+}
+
+impl<T: Timestamp> Matcher<T> for TimeMatcher<T> {
+    fn matches(&self, actual: &T) -> bool {
+        match *self {
+            TimeMatcher::Before(ref expected) => actual.offset_from(expected).1 == Ordering::Less,
+            TimeMatcher::After(ref expected) => actual.offset_from(expected).1 == Ordering::Greater,
+            TimeMatcher::WithinOfNow(tolerance) => actual.offset_from(&T::now()).0 <= tolerance,
+        }
+    }
+
+    fn failure_message(&self, actual: &T) -> String {
+        format!("expected time to {}, but it was {}", self.expectation(), self.found(actual))
+    }
+
+    fn negated_failure_message(&self, actual: &T) -> String {
+        format!("expected time not to {}, but it was {}", self.expectation(), self.found(actual))
+    }
+}
+
+#[derive(Debug)]
+pub struct Within {
+    tolerance: Duration,
+}
+
+impl Within {
+    pub fn new(tolerance: Duration) -> Within {
+        Within {
+            tolerance,
+        }
+    }
+
+    /// Matches durations that differ from the expected duration by at most the tolerance.
+    pub fn of(self, expected: Duration) -> DurationMatcher {
+        DurationMatcher::Within(self.tolerance, expected)
+    }
+
+    /// Matches a `SystemTime` or `Instant` that is at most the tolerance away from the time of
+    /// the assertion.
+    pub fn of_now<T>(self) -> TimeMatcher<T> {
+        TimeMatcher::WithinOfNow(self.tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant, SystemTime};
+    use dsl::Matcher;
+    use super::*;
+
+    #[test]
+    fn test_human_duration() {
+        assert_eq!(human_duration(Duration::from_nanos(750)), "750ns");
+        assert_eq!(human_duration(Duration::from_micros(12)), "12µs");
+        assert_eq!(human_duration(Duration::from_micros(1250)), "1.25ms");
+        assert_eq!(human_duration(Duration::from_millis(250)), "250ms");
+        assert_eq!(human_duration(Duration::from_millis(1500)), "1.5s");
+        assert_eq!(human_duration(Duration::from_secs(125)), "2m 5s");
+        assert_eq!(human_duration(Duration::from_secs(3600)), "1h");
+        assert_eq!(human_duration(Duration::from_millis(3_661_500)), "1h 1m 1.5s");
+        assert_eq!(human_duration(Duration::from_nanos(119_999_999_999)), "2m");
+        assert_eq!(human_duration(Duration::from_secs(0)), "0ns");
+    }
+
+    #[test]
+    fn test_human_duration_rounds_into_next_unit() {
+        assert_eq!(human_duration(Duration::from_nanos(999_999)), "999.999µs");
+        assert_eq!(human_duration(Duration::from_nanos(999_999_499)), "999.999ms");
+        assert_eq!(human_duration(Duration::from_nanos(999_999_600)), "1s");
+        assert_eq!(human_duration(Duration::from_nanos(59_999_600_000)), "1m");
+        assert_eq!(human_duration(Duration::from_nanos(59_999_400_000)), "59.999s");
+    }
+
+    #[test]
+    fn test_shorter_than_matches() {
+        let test = DurationMatcher::ShorterThan(Duration::from_secs(1));
+        assert!(test.matches(&Duration::from_millis(999)));
+        assert!(!test.matches(&Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_shorter_than_failure_msg() {
+        let test = DurationMatcher::ShorterThan(Duration::from_secs(1));
+        let msg = test.failure_message(&Duration::from_millis(1500));
+        assert_eq!(msg, String::from("expected duration 1.5s to be shorter than 1s"));
+    }
+
+    #[test]
+    fn test_longer_than_negated_failure_msg() {
+        let test = DurationMatcher::LongerThan(Duration::from_millis(100));
+        let msg = test.negated_failure_message(&Duration::from_millis(250));
+        assert_eq!(msg, String::from("expected duration 250ms not to be longer than 100ms"));
+    }
+
+    #[test]
+    fn test_within_matches() {
+        let test = Within::new(Duration::from_millis(100)).of(Duration::from_secs(1));
+        assert!(test.matches(&Duration::from_millis(900)));
+        assert!(test.matches(&Duration::from_millis(1100)));
+        assert!(!test.matches(&Duration::from_millis(1101)));
+    }
+
+    #[test]
+    fn test_within_failure_msg() {
+        let test = Within::new(Duration::from_millis(100)).of(Duration::from_secs(1));
+        let msg = test.failure_message(&Duration::from_millis(1200));
+        assert_eq!(msg, String::from("expected duration 1.2s to be within 100ms of 1s, but it differed by 200ms"));
+    }
+
+    #[test]
+    fn test_before_after_matches() {
+        let earlier = SystemTime::now();
+        let later = earlier + Duration::from_secs(5);
+        assert!(TimeMatcher::Before(later).matches(&earlier));
+        assert!(!TimeMatcher::Before(earlier).matches(&earlier));
+        assert!(TimeMatcher::After(earlier).matches(&later));
+
+        let start = Instant::now();
+        let end = start + Duration::from_millis(10);
+        assert!(TimeMatcher::Before(end).matches(&start));
+        assert!(!TimeMatcher::After(end).matches(&start));
+    }
+
+    #[test]
+    fn test_before_failure_msg() {
+        let expected = Instant::now();
+        let test = TimeMatcher::Before(expected);
+        let msg = test.failure_message(&(expected + Duration::from_millis(1500)));
+        assert_eq!(msg, String::from("expected time to be before the given time, but it was 1.5s after the given time"));
+    }
+
+    #[test]
+    fn test_after_negated_failure_msg() {
+        let expected = SystemTime::now();
+        let test = TimeMatcher::After(expected);
+        let msg = test.negated_failure_message(&(expected + Duration::from_secs(2)));
+        assert_eq!(msg, String::from("expected time not to be after the given time, but it was 2s after the given time"));
+    }
+
+    #[test]
+    fn test_within_of_now_matches() {
+        let test = Within::new(Duration::from_secs(1)).of_now();
+        assert!(test.matches(&SystemTime::now()));
+        assert!(!test.matches(&(SystemTime::now() - Duration::from_secs(5))));
+        assert!(Within::new(Duration::from_secs(1)).of_now().matches(&Instant::now()));
+    }
+
+    #[test]
+    fn test_within_of_now_failure_msg() {
+        let test = Within::new(Duration::from_secs(1)).of_now();
+        let msg = test.failure_message(&(SystemTime::now() + Duration::from_secs(30)));
+        assert!(msg.starts_with("expected time to be within 1s of now, but it was "), "{}", msg);
+        assert!(msg.ends_with("s after now"), "{}", msg);
+    }
+}
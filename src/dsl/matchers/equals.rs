@@ -1,4 +1,6 @@
+use std::borrow::Borrow;
 use std::fmt::Debug;
+use std::ops::Deref;
 use dsl::Matcher;
 
 pub struct Equals<'a, E: 'a> {
@@ -6,9 +8,9 @@ pub struct Equals<'a, E: 'a> {
 }
 
 impl<'a, E> Equals<'a, E> {
-    pub fn new(expected: &'a E) -> Equals<E> {
+    pub fn new(expected: &'a E) -> Equals<'a, E> {
         Equals{
-            expected: expected,
+            expected,
         }
     }
 }
@@ -27,8 +29,198 @@ impl<'a, E: Debug + PartialEq> Matcher<E> for Equals<'a, E> {
     }
 }
 
+pub struct EqualsValue<E> {
+    expected: E,
+}
+
+impl<E> EqualsValue<E> {
+    pub fn new(expected: E) -> EqualsValue<E> {
+        EqualsValue {
+            expected,
+        }
+    }
+}
+
+impl<A, E> Matcher<A> for EqualsValue<E> where A: Debug + PartialEq<E>, E: Debug {
+    fn matches(&self, actual: &A) -> bool {
+        *actual == self.expected
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} to equal {:?}", self.expected, actual)
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to equal {:?}", self.expected, actual)
+    }
+}
+
+pub struct EqualsDeref<E> {
+    expected: E,
+}
+
+impl<E> EqualsDeref<E> {
+    pub fn new(expected: E) -> EqualsDeref<E> {
+        EqualsDeref {
+            expected,
+        }
+    }
+}
+
+// Compares what the actual value points to, since std only compares a `Box<T>` with another `Box<T>`.
+impl<A, E> Matcher<A> for EqualsDeref<E> where A: Debug + Deref, A::Target: PartialEq<E>, E: Debug {
+    fn matches(&self, actual: &A) -> bool {
+        **actual == self.expected
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} to equal {:?}", self.expected, actual)
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to equal {:?}", self.expected, actual)
+    }
+}
+
+pub struct EqualBy<E, F> {
+    expected: E,
+    comparator: F,
+}
+
+impl<E, F> EqualBy<E, F> {
+    pub fn new<A>(expected: E, comparator: F) -> EqualBy<E, F> where F: Fn(&A, &E) -> bool {
+        EqualBy {
+            expected,
+            comparator,
+        }
+    }
+}
+
+impl<A, E, F> Matcher<A> for EqualBy<E, F> where A: Debug, E: Debug, F: Fn(&A, &E) -> bool {
+    fn matches(&self, actual: &A) -> bool {
+        (self.comparator)(actual, &self.expected)
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} to equal {:?} (compared with a custom comparator)",
+                self.expected, actual)
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to equal {:?} (compared with a custom comparator)",
+                self.expected, actual)
+    }
+}
+
+pub struct EqualByKey<E, F> {
+    expected: E,
+    key: F,
+}
+
+impl<E, F> EqualByKey<E, F> {
+    pub fn new(expected: E, key: F) -> EqualByKey<E, F> {
+        EqualByKey {
+            expected,
+            key,
+        }
+    }
+}
+
+impl<E, F> EqualByKey<E, F> {
+    // The expected value is either an `A` or a reference to one, so the key function applies to both.
+    fn comparison<A, K>(&self, actual: &A) -> String where E: Borrow<A>, F: Fn(&A) -> K, K: Debug {
+        format!("compared by key: expected key {:?}, actual key {:?}",
+                (self.key)(self.expected.borrow()), (self.key)(actual))
+    }
+}
+
+impl<A, E, F, K> Matcher<A> for EqualByKey<E, F>
+where A: Debug, E: Borrow<A> + Debug, F: Fn(&A) -> K, K: Debug + PartialEq {
+    fn matches(&self, actual: &A) -> bool {
+        (self.key)(actual) == (self.key)(self.expected.borrow())
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} to equal {:?} ({})",
+                self.expected, actual, self.comparison(actual))
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to equal {:?} ({})",
+                self.expected, actual, self.comparison(actual))
+    }
+}
+
+pub struct EqualIgnoringFields<E, F> {
+    expected: E,
+    fields: &'static [&'static str],
+    with_ignored_fields: F,
+}
+
+impl<E, F> EqualIgnoringFields<E, F> {
+    pub fn new<A>(expected: E, fields: &'static [&'static str], with_ignored_fields: F) -> EqualIgnoringFields<E, F>
+    where F: Fn(&A, &A) -> A {
+        EqualIgnoringFields {
+            expected,
+            fields,
+            with_ignored_fields,
+        }
+    }
+
+    fn comparison(&self) -> String {
+        let fields: Vec<String> = self.fields.iter().map(|field| format!("`{}`", field)).collect();
+        format!("compared ignoring fields {}", fields.join(", "))
+    }
+}
+
+// `with_ignored_fields` returns a copy of the actual value with the ignored fields taken from the
+// expected value, so that the remaining fields are compared with PartialEq.
+impl<A, E, F> Matcher<A> for EqualIgnoringFields<E, F>
+where A: Debug + PartialEq, E: Borrow<A> + Debug, F: Fn(&A, &A) -> A {
+    fn matches(&self, actual: &A) -> bool {
+        let expected = self.expected.borrow();
+        (self.with_ignored_fields)(actual, expected) == *expected
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} to equal {:?} ({})", self.expected, actual, self.comparison())
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to equal {:?} ({})", self.expected, actual, self.comparison())
+    }
+}
+
+/// Matcher for structs that compares every field except the named ones with PartialEq.
+///
+/// The fields are named after the struct's type, which must implement Clone and PartialEq, so
+/// misspelled fields fail to compile.
+///
+/// ```
+/// #[macro_use] extern crate ferrous;
+/// use ferrous::dsl::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Event { id: u32, name: &'static str, created_at: u64 }
+///
+/// # fn main() {
+/// let expected = Event { id: 0, name: "start", created_at: 0 };
+/// expect(&Event { id: 42, name: "start", created_at: 1700 })
+///     .to(equal_ignoring_fields!(&expected, Event { id, created_at }));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! equal_ignoring_fields {
+    ($expected:expr, $t:path { $($field:ident),+ $(,)* }) => {
+        $crate::dsl::__equal_ignoring_fields($expected, &[$(stringify!($field)),+], |actual: &$t, expected: &$t| {
+            $t { $($field: expected.$field.clone(),)+ ..actual.clone() }
+        })
+    };
+}
+
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
     use dsl::Matcher;
     use super::*;
 
@@ -54,4 +246,132 @@ mod tests {
         let msg = equals.negated_failure_message(&1);
         assert_eq!(msg, String::from("expected 1 not to equal 1"));
     }
+
+    #[test]
+    fn test_equal_value_matches() {
+        assert!(EqualsValue::new(1).matches(&1));
+        assert!(EqualsValue::new(5).matches(&5u8));
+        assert!(EqualsValue::new(vec![1, 2]).matches(&vec![1, 2]));
+        assert!(!EqualsValue::new(Some(1)).matches(&None));
+        assert!(EqualsValue::new("abc").matches(&String::from("abc")));
+        assert!(EqualsValue::new(&[1, 2][..]).matches(&vec![1, 2]));
+        assert!(!EqualsValue::new("abd").matches(&String::from("abc")));
+    }
+
+    #[test]
+    fn test_equal_deref_matches() {
+        assert!(EqualsDeref::new(5).matches(&Box::new(5)));
+        assert!(!EqualsDeref::new(6).matches(&Box::new(5)));
+        assert!(EqualsDeref::new("abc").matches(&Rc::new(String::from("abc"))));
+        assert!(EqualsDeref::new([1, 2]).matches(&vec![1, 2]));
+    }
+
+    #[test]
+    fn test_equal_deref_failure_msg() {
+        let msg = EqualsDeref::new(6).failure_message(&Box::new(5));
+        assert_eq!(msg, String::from("expected 6 to equal 5"));
+    }
+
+    #[test]
+    fn test_equal_deref_negated_failure_msg() {
+        let msg = EqualsDeref::new(5).negated_failure_message(&Box::new(5));
+        assert_eq!(msg, String::from("expected 5 not to equal 5"));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Celsius(i32);
+
+    #[test]
+    fn test_equal_value_custom_type_matches() {
+        assert!(EqualsValue::new(Celsius(20)).matches(&Celsius(20)));
+        assert!(!EqualsValue::new(Celsius(20)).matches(&Celsius(21)));
+    }
+
+    #[test]
+    fn test_equal_value_failure_msg() {
+        let msg = EqualsValue::new("abc").failure_message(&String::from("abd"));
+        assert_eq!(msg, String::from("expected \"abc\" to equal \"abd\""));
+    }
+
+    #[test]
+    fn test_equal_value_negated_failure_msg() {
+        let msg = EqualsValue::new(Celsius(20)).negated_failure_message(&Celsius(20));
+        assert_eq!(msg, String::from("expected Celsius(20) not to equal Celsius(20)"));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    struct Event {
+        id: u32,
+        name: &'static str,
+        tags: Vec<&'static str>,
+    }
+
+    #[test]
+    fn test_equal_by_matches() {
+        let test = EqualBy::new(1.0, |a: &f64, e: &f64| (a - e).abs() < 0.01);
+        assert!(test.matches(&1.001));
+        assert!(!test.matches(&1.1));
+    }
+
+    #[test]
+    fn test_equal_by_failure_msg() {
+        let test = EqualBy::new("abc", |a: &String, e: &&str| a.eq_ignore_ascii_case(e));
+        let msg = test.failure_message(&String::from("abd"));
+        assert_eq!(msg, String::from("expected \"abc\" to equal \"abd\" (compared with a custom comparator)"));
+    }
+
+    #[test]
+    fn test_equal_by_key_matches() {
+        let expected = Event { id: 1, name: "start", tags: vec![] };
+        let test = EqualByKey::new(&expected, |e: &Event| e.name);
+        assert!(test.matches(&Event { id: 2, name: "start", tags: vec!["a"] }));
+        assert!(!test.matches(&Event { id: 1, name: "stop", tags: vec![] }));
+    }
+
+    #[test]
+    fn test_equal_by_key_failure_msg() {
+        let test = EqualByKey::new(-3, |x: &i32| x.abs());
+        let msg = test.failure_message(&4);
+        assert_eq!(msg, String::from("expected -3 to equal 4 (compared by key: expected key 3, actual key 4)"));
+    }
+
+    #[test]
+    fn test_equal_by_key_negated_failure_msg() {
+        let test = EqualByKey::new(-3, |x: &i32| x.abs());
+        let msg = test.negated_failure_message(&3);
+        assert_eq!(msg, String::from("expected -3 not to equal 3 (compared by key: expected key 3, actual key 3)"));
+    }
+
+    #[test]
+    fn test_equal_ignoring_fields_matches() {
+        let expected = Event { id: 1, name: "start", tags: vec!["a"] };
+        let test = equal_ignoring_fields!(&expected, Event { id });
+        assert!(test.matches(&Event { id: 2, name: "start", tags: vec!["a"] }));
+        assert!(!test.matches(&Event { id: 2, name: "start", tags: vec!["b"] }));
+
+        let test = equal_ignoring_fields!(expected.clone(), Event { id, tags });
+        assert!(test.matches(&Event { id: 2, name: "start", tags: vec!["b", "c"] }));
+        assert!(!test.matches(&Event { id: 2, name: "stop", tags: vec!["a"] }));
+    }
+
+    #[test]
+    fn test_equal_ignoring_fields_failure_msg() {
+        let expected = Event { id: 1, name: "start", tags: vec![] };
+        let test = equal_ignoring_fields!(&expected, Event { id, tags });
+        let msg = test.failure_message(&Event { id: 2, name: "stop", tags: vec![] });
+        assert_eq!(msg, String::from("expected Event { id: 1, name: \"start\", tags: [] } to equal \
+                                      Event { id: 2, name: \"stop\", tags: [] } \
+                                      (compared ignoring fields `id`, `tags`)"));
+    }
+
+    #[test]
+    fn test_equal_ignoring_fields_negated_failure_msg() {
+        let expected = Event { id: 1, name: "start", tags: vec![] };
+        let test = equal_ignoring_fields!(&expected, Event { id });
+        let msg = test.negated_failure_message(&Event { id: 2, name: "start", tags: vec![] });
+        assert_eq!(msg, String::from("expected Event { id: 1, name: \"start\", tags: [] } not to equal \
+                                      Event { id: 2, name: \"start\", tags: [] } \
+                                      (compared ignoring fields `id`)"));
+    }
 }
@@ -0,0 +1,317 @@
+use std::any;
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::{io, num, str, string};
+use std::marker::PhantomData;
+use dsl::Matcher;
+
+/// Conversion to a `std::error::Error` trait object.
+///
+/// Implemented for boxed errors and the standard library error types. A blanket implementation
+/// over every `Error` type would conflict with the boxed ones, so other concrete errors need to
+/// be boxed before matching.
+pub trait AsError {
+    /// The value as an error trait object.
+    fn as_error(&self) -> &(dyn Error + 'static);
+}
+
+macro_rules! as_error_impls {
+    ($($t:ty),*) => {
+        $(
+            impl AsError for $t {
+                fn as_error(&self) -> &(dyn Error + 'static) {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! as_error_box_impls {
+    ($($t:ty),*) => {
+        $(
+            impl AsError for Box<$t> {
+                fn as_error(&self) -> &(dyn Error + 'static) {
+                    &**self
+                }
+            }
+        )*
+    };
+}
+
+as_error_box_impls!(dyn Error, dyn Error + Send, dyn Error + Send + Sync);
+
+as_error_impls!(io::Error, fmt::Error, num::ParseIntError, num::ParseFloatError,
+                str::ParseBoolError, str::Utf8Error, string::FromUtf8Error);
+
+// The given error followed by each of its sources.
+fn chain<'a>(err: &'a (dyn Error + 'static)) -> Vec<&'a (dyn Error + 'static)> {
+    let mut chain = vec![err];
+    let mut current = err;
+    while let Some(source) = current.source() {
+        chain.push(source);
+        current = source;
+    }
+    chain
+}
+
+fn render_chain(err: &(dyn Error + 'static)) -> String {
+    let messages: Vec<String> = chain(err).iter()
+        .map(|e| format!("{:?}", e.to_string()))
+        .collect();
+    messages.join(" caused by ")
+}
+
+pub struct ErrWithMessage<M> {
+    matcher: M,
+}
+
+impl<M> ErrWithMessage<M> {
+    pub fn new(matcher: M) -> ErrWithMessage<M> {
+        ErrWithMessage {
+            matcher,
+        }
+    }
+}
+
+impl<O: Debug, E: AsError, M: Matcher<String>> Matcher<Result<O, E>> for ErrWithMessage<M> {
+    fn matches(&self, actual: &Result<O, E>) -> bool {
+        match *actual {
+            Ok(_) => false,
+            Err(ref err) => self.matcher.matches(&err.as_error().to_string()),
+        }
+    }
+
+    fn failure_message(&self, actual: &Result<O, E>) -> String {
+        match *actual {
+            Ok(ref value) => format!("expected Err variant, found Ok({:?})", value),
+            Err(ref err) => {
+                let err = err.as_error();
+                format!("expected Err variant whose message matches: {}, found error {}",
+                        self.matcher.failure_message(&err.to_string()), render_chain(err))
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &Result<O, E>) -> String {
+        match *actual {
+            Ok(ref value) => format!("expected Err variant, found Ok({:?})", value),
+            Err(ref err) => {
+                let err = err.as_error();
+                format!("expected Err variant whose message does not match: {}, found error {}",
+                        self.matcher.negated_failure_message(&err.to_string()), render_chain(err))
+            },
+        }
+    }
+}
+
+pub struct ErrorSourceMatching<M> {
+    matcher: M,
+}
+
+impl<M> ErrorSourceMatching<M> {
+    pub fn new(matcher: M) -> ErrorSourceMatching<M> {
+        ErrorSourceMatching {
+            matcher,
+        }
+    }
+}
+
+impl<M: Matcher<String>> ErrorSourceMatching<M> {
+    fn source_messages<E: AsError>(&self, actual: &E) -> Vec<String> {
+        chain(actual.as_error()).iter()
+            .skip(1)
+            .map(|e| e.to_string())
+            .collect()
+    }
+}
+
+impl<E: AsError, M: Matcher<String>> Matcher<E> for ErrorSourceMatching<M> {
+    fn matches(&self, actual: &E) -> bool {
+        self.source_messages(actual).iter().any(|msg| self.matcher.matches(msg))
+    }
+
+    fn failure_message(&self, actual: &E) -> String {
+        let failures: Vec<String> = self.source_messages(actual).iter()
+            .map(|msg| self.matcher.failure_message(msg))
+            .collect();
+        if failures.is_empty() {
+            format!("expected a source of error {} to match, found no sources",
+                    render_chain(actual.as_error()))
+        } else {
+            format!("expected a source of error {} to match, found none: {}",
+                    render_chain(actual.as_error()), failures.join("; "))
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &E) -> String {
+        let matching: Vec<String> = self.source_messages(actual).iter()
+            .filter(|msg| self.matcher.matches(msg))
+            .map(|msg| self.matcher.negated_failure_message(msg))
+            .collect();
+        format!("expected no source of error {} to match, found: {}",
+                render_chain(actual.as_error()), matching.join("; "))
+    }
+}
+
+pub struct ErrorOfType<T> {
+    error: PhantomData<T>,
+}
+
+impl<T> ErrorOfType<T> {
+    pub fn new() -> ErrorOfType<T> {
+        ErrorOfType {
+            error: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for ErrorOfType<T> {
+    fn default() -> ErrorOfType<T> {
+        ErrorOfType::new()
+    }
+}
+
+impl<E: AsError, T: Error + 'static> Matcher<E> for ErrorOfType<T> {
+    fn matches(&self, actual: &E) -> bool {
+        actual.as_error().is::<T>()
+    }
+
+    fn failure_message(&self, actual: &E) -> String {
+        format!("expected error of type {}, found error {}",
+                any::type_name::<T>(), render_chain(actual.as_error()))
+    }
+
+    fn negated_failure_message(&self, actual: &E) -> String {
+        format!("expected error not of type {}, found error {}",
+                any::type_name::<T>(), render_chain(actual.as_error()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::fmt;
+    use dsl::Matcher;
+    use dsl::matchers::Equals;
+    use super::*;
+
+    #[derive(Debug)]
+    struct Inner;
+
+    impl fmt::Display for Inner {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "connection refused")
+        }
+    }
+
+    impl Error for Inner {}
+
+    #[derive(Debug)]
+    struct Outer(Inner);
+
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "request failed")
+        }
+    }
+
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    fn boxed() -> Box<dyn Error> {
+        Box::new(Outer(Inner))
+    }
+
+    #[test]
+    fn test_std_error_matches() {
+        let expected = String::from("invalid digit found in string");
+        let test = ErrWithMessage::new(Equals::new(&expected));
+        assert!(test.matches(&"x".parse::<u8>()));
+    }
+
+    #[test]
+    fn test_err_with_message_matches() {
+        let expected = String::from("request failed");
+        let test = ErrWithMessage::new(Equals::new(&expected));
+        let send: Result<u8, Box<dyn Error + Send>> = Err(Box::new(Outer(Inner)));
+        let ok: Result<u8, Box<dyn Error>> = Ok(1);
+        assert!(test.matches(&Err(boxed()) as &Result<u8, Box<dyn Error>>));
+        assert!(test.matches(&send));
+        assert!(!test.matches(&ok));
+    }
+
+    #[test]
+    fn test_err_with_message_failure_msg() {
+        let expected = String::from("timed out");
+        let test = ErrWithMessage::new(Equals::new(&expected));
+        let msg = test.failure_message(&Err(boxed()) as &Result<u8, Box<dyn Error>>);
+        assert_eq!(msg, String::from("expected Err variant whose message matches: \
+                                      expected \"timed out\" to equal \"request failed\", \
+                                      found error \"request failed\" caused by \"connection refused\""));
+    }
+
+    #[test]
+    fn test_err_with_message_ok_failure_msg() {
+        let expected = String::from("timed out");
+        let test = ErrWithMessage::new(Equals::new(&expected));
+        let ok: Result<u8, Box<dyn Error>> = Ok(1);
+        let msg = test.failure_message(&ok);
+        assert_eq!(msg, String::from("expected Err variant, found Ok(1)"));
+    }
+
+    #[test]
+    fn test_error_source_matches() {
+        let expected = String::from("connection refused");
+        let test = ErrorSourceMatching::new(Equals::new(&expected));
+        assert!(test.matches(&boxed()));
+        assert!(!test.matches(&(Box::new(Inner) as Box<dyn Error>)));
+    }
+
+    #[test]
+    fn test_error_source_failure_msg() {
+        let expected = String::from("timed out");
+        let test = ErrorSourceMatching::new(Equals::new(&expected));
+        let msg = test.failure_message(&boxed());
+        assert_eq!(msg, String::from("expected a source of error \"request failed\" caused by \
+                                      \"connection refused\" to match, found none: \
+                                      expected \"timed out\" to equal \"connection refused\""));
+    }
+
+    #[test]
+    fn test_error_source_no_sources_failure_msg() {
+        let expected = String::from("timed out");
+        let test = ErrorSourceMatching::new(Equals::new(&expected));
+        let msg = test.failure_message(&(Box::new(Inner) as Box<dyn Error>));
+        assert_eq!(msg, String::from("expected a source of error \"connection refused\" to match, \
+                                      found no sources"));
+    }
+
+    #[test]
+    fn test_error_source_negated_failure_msg() {
+        let expected = String::from("connection refused");
+        let test = ErrorSourceMatching::new(Equals::new(&expected));
+        let msg = test.negated_failure_message(&boxed());
+        assert_eq!(msg, String::from("expected no source of error \"request failed\" caused by \
+                                      \"connection refused\" to match, found: \
+                                      expected \"connection refused\" not to equal \"connection refused\""));
+    }
+
+    #[test]
+    fn test_error_of_type_matches() {
+        let test = ErrorOfType::<Outer>::new();
+        assert!(test.matches(&boxed()));
+        assert!(!test.matches(&(Box::new(Inner) as Box<dyn Error + Send + Sync>)));
+    }
+
+    #[test]
+    fn test_error_of_type_failure_msg() {
+        let test = ErrorOfType::<Inner>::new();
+        let msg = test.failure_message(&boxed());
+        assert!(msg.starts_with("expected error of type "));
+        assert!(msg.ends_with("::Inner, found error \"request failed\" caused by \"connection refused\""));
+    }
+}
@@ -0,0 +1,219 @@
+use dsl::Matcher;
+
+pub struct HasField<F, M> {
+    name: &'static str,
+    accessor: F,
+    matcher: M,
+}
+
+impl<F, M> HasField<F, M> {
+    pub fn new<A, T>(name: &'static str, accessor: F, matcher: M) -> HasField<F, M>
+    where F: Fn(&A) -> &T {
+        HasField {
+            name,
+            accessor,
+            matcher,
+        }
+    }
+}
+
+impl<A, T, F, M> Matcher<A> for HasField<F, M>
+where F: Fn(&A) -> &T, M: Matcher<T> {
+    fn matches(&self, actual: &A) -> bool {
+        self.matcher.matches((self.accessor)(actual))
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        self.matcher.field_failure_message(self.name, (self.accessor)(actual), false)
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        self.matcher.field_failure_message(self.name, (self.accessor)(actual), true)
+    }
+
+    // Nested field matchers report the full path from the outermost field.
+    fn field_failure_message(&self, path: &str, actual: &A, negated: bool) -> String {
+        let path = format!("{}.{}", path, self.name);
+        self.matcher.field_failure_message(&path, (self.accessor)(actual), negated)
+    }
+}
+
+pub struct HasFields<'a, A> {
+    fields: Vec<Box<dyn Matcher<A> + 'a>>,
+}
+
+impl<'a, A> HasFields<'a, A> {
+    pub fn new() -> HasFields<'a, A> {
+        HasFields {
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn field<T, F, M>(mut self, name: &'static str, accessor: F, matcher: M) -> HasFields<'a, A>
+    where F: 'a + Fn(&A) -> &T, M: 'a + Matcher<T>, T: 'a {
+        self.fields.push(Box::new(HasField::new(name, accessor, matcher)));
+        self
+    }
+}
+
+impl<'a, A> Default for HasFields<'a, A> {
+    fn default() -> HasFields<'a, A> {
+        HasFields::new()
+    }
+}
+
+impl<'a, A> Matcher<A> for HasFields<'a, A> {
+    fn matches(&self, actual: &A) -> bool {
+        self.fields.iter().all(|field| field.matches(actual))
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let failures: Vec<String> = self.fields.iter()
+            .filter(|field| !field.matches(actual))
+            .map(|field| field.failure_message(actual))
+            .collect();
+        failures.join("; ")
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let failures: Vec<String> = self.fields.iter()
+            .map(|field| field.negated_failure_message(actual))
+            .collect();
+        failures.join("; ")
+    }
+
+    fn field_failure_message(&self, path: &str, actual: &A, negated: bool) -> String {
+        let failures: Vec<String> = self.fields.iter()
+            .filter(|field| negated || !field.matches(actual))
+            .map(|field| field.field_failure_message(path, actual, negated))
+            .collect();
+        failures.join("; ")
+    }
+}
+
+/// Matcher that asserts several fields of a struct at once.
+///
+/// Each field is given as a path relative to the struct, which may reach into nested structs,
+/// followed by the matcher for its value. Every failing field is reported with its path.
+///
+/// ```
+/// #[macro_use] extern crate ferrous;
+/// use ferrous::dsl::*;
+///
+/// struct Address { zip: u32 }
+/// struct User { name: &'static str, address: Address }
+///
+/// # fn main() {
+/// let user = User { name: "bob", address: Address { zip: 12345 } };
+/// expect(&user).to(has_fields!(User {
+///     name: equal(&"bob"),
+///     address.zip: equal(&12345),
+/// }));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! has_fields {
+    ($t:ty { $($($field:ident).+ : $matcher:expr),+ $(,)* }) => {
+        $crate::dsl::__has_fields::<$t>()
+            $(.field(stringify!($($field).+), |actual: &$t| &actual.$($field).+, $matcher))+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use dsl::matchers::Equals;
+    use super::*;
+
+    #[derive(Debug)]
+    struct Address {
+        zip: u32,
+    }
+
+    #[derive(Debug)]
+    struct User {
+        name: &'static str,
+        address: Address,
+    }
+
+    fn user() -> User {
+        User {
+            name: "bob",
+            address: Address {
+                zip: 12345,
+            },
+        }
+    }
+
+    #[test]
+    fn test_has_field_matches() {
+        let expected = "bob";
+        let test = HasField::new("name", |u: &User| &u.name, Equals::new(&expected));
+        assert!(test.matches(&user()));
+    }
+
+    #[test]
+    fn test_has_field_failure_msg() {
+        let expected = "alice";
+        let test = HasField::new("name", |u: &User| &u.name, Equals::new(&expected));
+        let msg = test.failure_message(&user());
+        assert_eq!(msg, String::from("expected field `name` to match: expected \"alice\" to equal \"bob\""));
+    }
+
+    #[test]
+    fn test_has_field_negated_failure_msg() {
+        let expected = "bob";
+        let test = HasField::new("name", |u: &User| &u.name, Equals::new(&expected));
+        let msg = test.negated_failure_message(&user());
+        assert_eq!(msg, String::from("expected field `name` not to match: expected \"bob\" not to equal \"bob\""));
+    }
+
+    #[test]
+    fn test_nested_has_field_failure_msg() {
+        let expected = 1;
+        let test = HasField::new("user", |u: &User| u,
+            HasField::new("address", |u: &User| &u.address,
+                HasField::new("zip", |a: &Address| &a.zip, Equals::new(&expected))));
+        let msg = test.failure_message(&user());
+        assert_eq!(msg, String::from("expected field `user.address.zip` to match: expected 1 to equal 12345"));
+    }
+
+    #[test]
+    fn test_nested_has_fields_failure_msg() {
+        let test = HasField::new("user", |u: &User| u, has_fields!(User {
+            name: Equals::new(&"alice"),
+            address.zip: Equals::new(&12345),
+        }));
+        let msg = test.failure_message(&user());
+        assert_eq!(msg, String::from("expected field `user.name` to match: expected \"alice\" to equal \"bob\""));
+
+        let msg = test.negated_failure_message(&user());
+        assert_eq!(msg, String::from("expected field `user.name` not to match: expected \"alice\" not to equal \"bob\"; \
+                                      expected field `user.address.zip` not to match: expected 12345 not to equal 12345"));
+    }
+
+    #[test]
+    fn test_field_message_not_mistaken_for_path() {
+        let expected = String::from("expected field `x");
+        let test = HasField::new("name", |u: &String| u, Equals::new(&expected));
+        let msg = test.failure_message(&String::from("y"));
+        assert_eq!(msg, String::from("expected field `name` to match: expected \"expected field `x\" to equal \"y\""));
+    }
+
+    #[test]
+    fn test_has_fields_matches() {
+        let test = has_fields!(User { name: Equals::new(&"bob"), address.zip: Equals::new(&12345) });
+        assert!(test.matches(&user()));
+    }
+
+    #[test]
+    fn test_has_fields_failure_msg() {
+        let test = has_fields!(User {
+            name: Equals::new(&"alice"),
+            address.zip: Equals::new(&1),
+        });
+        let msg = test.failure_message(&user());
+        assert_eq!(msg, String::from("expected field `name` to match: expected \"alice\" to equal \"bob\"; \
+                                      expected field `address.zip` to match: expected 1 to equal 12345"));
+    }
+}
@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::str;
+use diff::unified_diff;
+use dsl::Matcher;
+use dsl::matchers::BytesMatcher;
+
+fn normalize(bytes: &[u8]) -> Cow<'_, [u8]> {
+    if !bytes.windows(2).any(|pair| pair == b"\r\n") {
+        return Cow::Borrowed(bytes)
+    }
+
+    let mut normalized = Vec::with_capacity(bytes.len());
+    for (idx, &byte) in bytes.iter().enumerate() {
+        if byte != b'\r' || bytes.get(idx + 1) != Some(&b'\n') {
+            normalized.push(byte);
+        }
+    }
+    Cow::Owned(normalized)
+}
+
+#[derive(Debug)]
+pub struct GoldenFile {
+    path: PathBuf,
+    normalize_line_endings: bool,
+}
+
+impl GoldenFile {
+    pub fn new(path: PathBuf) -> GoldenFile {
+        GoldenFile {
+            path,
+            normalize_line_endings: false,
+        }
+    }
+
+    /// Treats `\r\n` and `\n` line endings as equal on both sides.
+    pub fn normalizing_line_endings(mut self) -> GoldenFile {
+        self.normalize_line_endings = true;
+        self
+    }
+
+    // The sibling file the actual output is written to, e.g. `expected.txt.actual`.
+    fn actual_path(&self) -> PathBuf {
+        let mut name = self.path.file_name().map(|n| n.to_owned()).unwrap_or_default();
+        name.push(".actual");
+        self.path.with_file_name(name)
+    }
+
+    fn prepare<'b>(&self, bytes: &'b [u8]) -> Cow<'b, [u8]> {
+        if self.normalize_line_endings {
+            normalize(bytes)
+        } else {
+            Cow::Borrowed(bytes)
+        }
+    }
+
+    fn read(&self) -> io::Result<Vec<u8>> {
+        fs::read(&self.path)
+    }
+
+    // Leaves the actual output next to the golden file so it can be inspected or copied over, and
+    // describes where it went.
+    fn write_actual(&self, actual: &[u8]) -> String {
+        let actual_path = self.actual_path();
+        match fs::write(&actual_path, actual) {
+            Ok(()) => format!("actual output written to {:?}", actual_path),
+            Err(err) => format!("actual output could not be written to {:?}: {}", actual_path, err),
+        }
+    }
+}
+
+impl<A: AsRef<[u8]>> Matcher<A> for GoldenFile {
+    fn matches(&self, actual: &A) -> bool {
+        match self.read() {
+            Ok(expected) => self.prepare(&expected) == self.prepare(actual.as_ref()),
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let written = self.write_actual(actual.as_ref());
+        let expected = match self.read() {
+            Ok(expected) => expected,
+            Err(err) => return format!("expected golden file {:?} to be readable, found error: {} ({})",
+                                       self.path, err, written),
+        };
+
+        let expected = self.prepare(&expected);
+        let actual = self.prepare(actual.as_ref());
+        let difference = match (str::from_utf8(&expected), str::from_utf8(&actual)) {
+            (Ok(expected), Ok(actual)) => {
+                let actual_label = self.actual_path();
+                unified_diff(expected, actual, &self.path.to_string_lossy(), &actual_label.to_string_lossy())
+            },
+            _ => BytesMatcher::Equal(expected.clone()).failure_message(&actual),
+        };
+        format!("expected output to match golden file {:?} ({}):\n{}", self.path, written, difference)
+    }
+
+    fn negated_failure_message(&self, _actual: &A) -> String {
+        format!("expected output not to match golden file {:?}", self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use dsl::Matcher;
+    use super::*;
+
+    // Removes its scratch directory when the test ends, whether or not it passed.
+    struct ScratchDir(PathBuf);
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // A fresh directory containing a golden file `expected.txt` with the given contents.
+    fn scratch(name: &str, contents: &[u8]) -> (ScratchDir, PathBuf) {
+        let dir = env::temp_dir().join(format!("ferrous-golden-{}-{}", ::std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("expected.txt");
+        fs::write(&path, contents).unwrap();
+        (ScratchDir(dir), path)
+    }
+
+    #[test]
+    fn test_golden_file_matches() {
+        let (_dir, path) = scratch("matches", b"one\ntwo\n");
+        let test = GoldenFile::new(path.clone());
+        assert!(test.matches(&"one\ntwo\n"));
+        assert!(test.matches(&b"one\ntwo\n".to_vec()));
+        assert!(!test.matches(&String::from("one\r\ntwo\r\n")));
+        assert!(!path.with_file_name("expected.txt.actual").exists());
+    }
+
+    #[test]
+    fn test_golden_file_normalizing_line_endings_matches() {
+        let (_dir, path) = scratch("normalize", b"one\r\ntwo\n");
+        let test = GoldenFile::new(path).normalizing_line_endings();
+        assert!(test.matches(&"one\ntwo\r\n"));
+        assert!(!test.matches(&"one\ntwo\n\n"));
+    }
+
+    #[test]
+    fn test_golden_file_failure_msg() {
+        let (_dir, path) = scratch("failure", b"one\ntwo\nthree\n");
+        let test = GoldenFile::new(path.clone());
+        let msg = test.failure_message(&"one\n2\nthree\n");
+        let actual_path = path.with_file_name("expected.txt.actual");
+        assert_eq!(msg, format!("expected output to match golden file {:?} (actual output written to {:?}):\n\
+                                 --- {}\n+++ {}\n@@ -1,3 +1,3 @@\n one\n-two\n+2\n three",
+                                path, actual_path, path.display(), actual_path.display()));
+        assert_eq!(fs::read_to_string(&actual_path).unwrap(), "one\n2\nthree\n");
+    }
+
+    #[test]
+    fn test_golden_file_line_endings_failure_msg() {
+        let (_dir, path) = scratch("line_endings", b"one\ntwo\n");
+        let test = GoldenFile::new(path.clone());
+        let msg = test.failure_message(&"one\r\ntwo\r\n");
+        let actual_path = path.with_file_name("expected.txt.actual");
+        assert_eq!(msg, format!("expected output to match golden file {:?} (actual output written to {:?}):\n\
+                                 --- {}\n+++ {}\n@@ -1,2 +1,2 @@\n-one\n-two\n+one\\r\n+two\\r",
+                                path, actual_path, path.display(), actual_path.display()));
+    }
+
+    #[test]
+    fn test_golden_file_bytes_failure_msg() {
+        let (_dir, path) = scratch("bytes", &[0xff, 0x00]);
+        let test = GoldenFile::new(path);
+        let msg = test.failure_message(&vec![0xffu8, 0x01]);
+        assert!(msg.contains("expected bytes to be equal, found 1 differing bytes"));
+    }
+
+    #[test]
+    fn test_golden_file_missing_failure_msg() {
+        let (_dir, path) = scratch("missing", b"");
+        let path = path.with_file_name("missing.txt");
+        let test = GoldenFile::new(path.clone());
+        let msg = test.failure_message(&"output");
+        assert!(msg.starts_with(&format!("expected golden file {:?} to be readable, found error: ", path)));
+        assert!(msg.ends_with(&format!("(actual output written to {:?})", path.with_file_name("missing.txt.actual"))));
+    }
+
+    #[test]
+    fn test_golden_file_negated_failure_msg() {
+        let (_dir, path) = scratch("negated", b"same");
+        let test = GoldenFile::new(path.clone());
+        let msg = test.negated_failure_message(&"same");
+        assert_eq!(msg, format!("expected output not to match golden file {:?}", path));
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::Debug;
+use std::io::{self, ErrorKind};
+use dsl::Matcher;
+
+fn describe(err: &io::Error) -> String {
+    let os_error = match err.raw_os_error() {
+        Some(code) => code.to_string(),
+        None => String::from("none"),
+    };
+    format!("io error (kind: {:?}, raw os error: {}, description: {:?})",
+            err.kind(), os_error, err.to_string())
+}
+
+#[derive(Debug)]
+pub enum IoErrorMatcher {
+    Kind(ErrorKind),
+    OsError(i32),
+}
+
+impl IoErrorMatcher {
+    fn expectation(&self) -> String {
+        match *self {
+            IoErrorMatcher::Kind(kind) => format!("of kind {:?}", kind),
+            IoErrorMatcher::OsError(code) => format!("with raw os error {}", code),
+        }
+    }
+
+    fn matches_error(&self, err: &io::Error) -> bool {
+        match *self {
+            IoErrorMatcher::Kind(kind) => err.kind() == kind,
+            IoErrorMatcher::OsError(code) => err.raw_os_error() == Some(code),
+        }
+    }
+}
+
+impl Matcher<io::Error> for IoErrorMatcher {
+    fn matches(&self, actual: &io::Error) -> bool {
+        self.matches_error(actual)
+    }
+
+    fn failure_message(&self, actual: &io::Error) -> String {
+        format!("expected io error {}, found {}", self.expectation(), describe(actual))
+    }
+
+    fn negated_failure_message(&self, actual: &io::Error) -> String {
+        format!("expected io error not {}, found {}", self.expectation(), describe(actual))
+    }
+}
+
+impl<T: Debug> Matcher<io::Result<T>> for IoErrorMatcher {
+    fn matches(&self, actual: &io::Result<T>) -> bool {
+        match *actual {
+            Ok(_) => false,
+            Err(ref err) => self.matches_error(err),
+        }
+    }
+
+    fn failure_message(&self, actual: &io::Result<T>) -> String {
+        match *actual {
+            Ok(ref value) => {
+                format!("expected io error {}, found Ok({:?})", self.expectation(), value)
+            },
+            Err(ref err) => self.failure_message(err),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &io::Result<T>) -> String {
+        match *actual {
+            Ok(ref value) => {
+                format!("expected io error not {}, found Ok({:?})", self.expectation(), value)
+            },
+            Err(ref err) => self.negated_failure_message(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, ErrorKind};
+    use dsl::Matcher;
+    use super::*;
+
+    #[test]
+    fn test_kind_matches() {
+        let test = IoErrorMatcher::Kind(ErrorKind::NotFound);
+        assert!(test.matches(&io::Error::new(ErrorKind::NotFound, "missing")));
+        assert!(!test.matches(&io::Error::other("other")));
+    }
+
+    #[test]
+    fn test_kind_result_matches() {
+        let test = IoErrorMatcher::Kind(ErrorKind::NotFound);
+        let err: io::Result<u8> = Err(io::Error::new(ErrorKind::NotFound, "missing"));
+        let ok: io::Result<u8> = Ok(1);
+        assert!(test.matches(&err));
+        assert!(!test.matches(&ok));
+    }
+
+    #[test]
+    fn test_kind_failure_msg() {
+        let test = IoErrorMatcher::Kind(ErrorKind::NotFound);
+        let msg = test.failure_message(&io::Error::new(ErrorKind::PermissionDenied, "denied"));
+        assert_eq!(msg, String::from("expected io error of kind NotFound, found io error \
+                                      (kind: PermissionDenied, raw os error: none, description: \"denied\")"));
+    }
+
+    #[test]
+    fn test_kind_negated_failure_msg() {
+        let test = IoErrorMatcher::Kind(ErrorKind::NotFound);
+        let msg = test.negated_failure_message(&io::Error::new(ErrorKind::NotFound, "missing"));
+        assert_eq!(msg, String::from("expected io error not of kind NotFound, found io error \
+                                      (kind: NotFound, raw os error: none, description: \"missing\")"));
+    }
+
+    #[test]
+    fn test_kind_result_failure_msg() {
+        let test = IoErrorMatcher::Kind(ErrorKind::NotFound);
+        let ok: io::Result<u8> = Ok(1);
+        let msg = test.failure_message(&ok);
+        assert_eq!(msg, String::from("expected io error of kind NotFound, found Ok(1)"));
+    }
+
+    #[test]
+    fn test_os_error_matches() {
+        let test = IoErrorMatcher::OsError(2);
+        assert!(test.matches(&io::Error::from_raw_os_error(2)));
+        assert!(!test.matches(&io::Error::from_raw_os_error(13)));
+        assert!(!test.matches(&io::Error::other("other")));
+    }
+
+    #[test]
+    fn test_os_error_failure_msg() {
+        let test = IoErrorMatcher::OsError(2);
+        let msg = test.failure_message(&io::Error::other("other"));
+        assert_eq!(msg, String::from("expected io error with raw os error 2, found io error \
+                                      (kind: Other, raw os error: none, description: \"other\")"));
+    }
+}
@@ -0,0 +1,185 @@
+use json::Json;
+use dsl::Matcher;
+
+const MAX_LISTED: usize = 16;
+
+pub struct MatchJson {
+    expected: Result<Json, String>,
+}
+
+impl MatchJson {
+    pub fn new(expected: &str) -> MatchJson {
+        MatchJson {
+            expected: Json::parse(expected),
+        }
+    }
+}
+
+impl<A: AsRef<str>> Matcher<A> for MatchJson {
+    fn matches(&self, actual: &A) -> bool {
+        match (&self.expected, Json::parse(actual.as_ref())) {
+            (Ok(expected), Ok(ref actual)) => expected.differences(actual).is_empty(),
+            _ => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        match (&self.expected, Json::parse(actual.as_ref())) {
+            (Err(err), _) => format!("expected JSON is invalid: {}", err),
+            (_, Err(err)) => format!("expected valid JSON, but parsing failed: {}", err),
+            (Ok(expected), Ok(ref actual)) => {
+                let differences = expected.differences(actual);
+                let mut listed: Vec<String> = differences.iter()
+                    .take(MAX_LISTED)
+                    .map(|(pointer, difference)| format!("  at {:?}: {}", pointer, difference))
+                    .collect();
+                if differences.len() > MAX_LISTED {
+                    listed.push(format!("  ... and {} more", differences.len() - MAX_LISTED));
+                }
+                format!("expected JSON to match, found {} differences:\n{}",
+                        differences.len(), listed.join("\n"))
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, _actual: &A) -> String {
+        match self.expected {
+            Ok(ref expected) => format!("expected JSON not to match {}", expected),
+            Err(ref err) => format!("expected JSON is invalid: {}", err),
+        }
+    }
+}
+
+pub struct HaveJsonPath<'a, M> {
+    pointer: &'a str,
+    matcher: M,
+}
+
+impl<'a, M> HaveJsonPath<'a, M> {
+    pub fn new(pointer: &'a str, matcher: M) -> HaveJsonPath<'a, M> {
+        HaveJsonPath {
+            pointer,
+            matcher,
+        }
+    }
+}
+
+impl<'a, A: AsRef<str>, M: Matcher<String>> Matcher<A> for HaveJsonPath<'a, M> {
+    fn matches(&self, actual: &A) -> bool {
+        match Json::parse(actual.as_ref()) {
+            Ok(json) => match json.pointer(self.pointer) {
+                Some(value) => self.matcher.matches(&value.to_string()),
+                None => false,
+            },
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        match Json::parse(actual.as_ref()) {
+            Ok(json) => match json.pointer(self.pointer) {
+                Some(value) => {
+                    format!("expected JSON at {:?} to match: {}",
+                            self.pointer, self.matcher.failure_message(&value.to_string()))
+                },
+                None => format!("expected JSON path {:?} to exist in {}", self.pointer, json),
+            },
+            Err(err) => format!("expected valid JSON, but parsing failed: {}", err),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        match Json::parse(actual.as_ref()) {
+            Ok(json) => match json.pointer(self.pointer) {
+                Some(value) => {
+                    format!("expected JSON at {:?} not to match: {}",
+                            self.pointer, self.matcher.negated_failure_message(&value.to_string()))
+                },
+                None => format!("expected JSON path {:?} not to exist in {}", self.pointer, json),
+            },
+            Err(err) => format!("expected valid JSON, but parsing failed: {}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use dsl::matchers::Equals;
+    use super::*;
+
+    #[test]
+    fn test_match_json_matches() {
+        let test = MatchJson::new("{\"a\": [1, 2], \"b\": {\"c\": null}}");
+        assert!(test.matches(&"{ \"b\": {\"c\": null},\n \"a\": [1, 2.0] }"));
+        assert!(test.matches(&String::from("{\"b\":{\"c\":null},\"a\":[1,2]}")));
+        assert!(!test.matches(&"{\"a\": [2, 1], \"b\": {\"c\": null}}"));
+        assert!(!test.matches(&"not json"));
+
+        let test = MatchJson::new("{\"id\": 9007199254740992}");
+        assert!(test.matches(&"{\"id\": 9007199254740992.0}"));
+        assert!(!test.matches(&"{\"id\": 9007199254740993}"));
+    }
+
+    #[test]
+    fn test_match_json_failure_msg() {
+        let test = MatchJson::new("{\"a\": [1, 2], \"b\": {\"c\": null}}");
+        let msg = test.failure_message(&"{\"a\": [1], \"b\": {\"c\": \"x\"}, \"d\": 1}");
+        assert_eq!(msg, String::from("expected JSON to match, found 3 differences:\n  \
+                                      at \"/a/1\": expected 2, found nothing\n  \
+                                      at \"/b/c\": expected null, found \"x\"\n  \
+                                      at \"/d\": unexpected 1"));
+    }
+
+    #[test]
+    fn test_match_json_many_differences_failure_msg() {
+        let expected: Vec<String> = (0..20).map(|i| format!("\"k{}\": {}", i, i)).collect();
+        let test = MatchJson::new(&format!("{{{}}}", expected.join(", ")));
+        let msg = test.failure_message(&"{}");
+        assert!(msg.starts_with("expected JSON to match, found 20 differences:\n  \
+                                 at \"/k0\": expected 0, found nothing\n"));
+        assert!(msg.ends_with("\n  at \"/k15\": expected 15, found nothing\n  ... and 4 more"));
+    }
+
+    #[test]
+    fn test_match_json_invalid_failure_msg() {
+        let test = MatchJson::new("{}");
+        let msg = test.failure_message(&"{\"a\"}");
+        assert_eq!(msg, String::from("expected valid JSON, but parsing failed: expected ':' at offset 4"));
+
+        let test = MatchJson::new("[");
+        let msg = test.failure_message(&"{}");
+        assert_eq!(msg, String::from("expected JSON is invalid: unexpected end of input at offset 1"));
+    }
+
+    #[test]
+    fn test_match_json_negated_failure_msg() {
+        let test = MatchJson::new("{ \"a\": 1 }");
+        let msg = test.negated_failure_message(&"{\"a\": 1}");
+        assert_eq!(msg, String::from("expected JSON not to match {\"a\":1}"));
+    }
+
+    #[test]
+    fn test_have_json_path_matches() {
+        let test = HaveJsonPath::new("/a/0/b", MatchJson::new("[true]"));
+        assert!(test.matches(&"{\"a\": [{\"b\": [true]}]}"));
+        assert!(!test.matches(&"{\"a\": [{\"b\": [false]}]}"));
+        assert!(!test.matches(&"{\"a\": []}"));
+    }
+
+    #[test]
+    fn test_have_json_path_failure_msg() {
+        let expected = String::from("\"bob\"");
+        let test = HaveJsonPath::new("/user/name", Equals::new(&expected));
+        let msg = test.failure_message(&"{\"user\": {\"name\": \"alice\"}}");
+        assert_eq!(msg, String::from("expected JSON at \"/user/name\" to match: \
+                                      expected \"\\\"bob\\\"\" to equal \"\\\"alice\\\"\""));
+    }
+
+    #[test]
+    fn test_have_json_path_missing_failure_msg() {
+        let test = HaveJsonPath::new("/a/1", MatchJson::new("1"));
+        let msg = test.failure_message(&"{\"a\": [0]}");
+        assert_eq!(msg, String::from("expected JSON path \"/a/1\" to exist in {\"a\":[0]}"));
+    }
+}
@@ -0,0 +1,359 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use dsl::Matcher;
+
+const NAMES: [&str; 3] = ["a", "b", "c"];
+
+// A broken law, the samples that break it and how.
+struct Violation<'a, T: 'a> {
+    law: &'static str,
+    samples: Vec<&'a T>,
+    detail: String,
+}
+
+impl<'a, T: Debug> Violation<'a, T> {
+    fn describe(&self, subject: &str) -> String {
+        let samples: Vec<String> = self.samples.iter().zip(NAMES.iter())
+            .map(|(sample, name)| format!("{} = {:?}", name, sample))
+            .collect();
+        format!("{} is not {}: {}, where {}", subject, self.law, samples.join(", "), self.detail)
+    }
+}
+
+type Check<'f, T> = &'f dyn Fn(&[&T]) -> Option<(&'static str, String)>;
+
+// Ordered pairs and triples of distinct indices below `n`.
+fn pairs(n: usize) -> impl Iterator<Item=(usize, usize)> {
+    (0..n).flat_map(move |i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+}
+
+fn triples(n: usize) -> impl Iterator<Item=(usize, usize, usize)> {
+    pairs(n).flat_map(move |(i, j)| (0..n).filter(move |&k| k != i && k != j).map(move |k| (i, j, k)))
+}
+
+// The first counterexample using the fewest samples: single samples, then pairs, then triples when
+// there is a law over three samples.
+fn counterexample<'a, T>(samples: &[&'a T], single: Check<T>, pair: Check<T>, triple: Option<Check<T>>)
+-> Option<Violation<'a, T>> {
+    let n = samples.len();
+    let violation = |check: Check<T>, picked: &[&'a T]| {
+        check(picked).map(|(law, detail)| Violation { law, samples: picked.to_vec(), detail })
+    };
+    (0..n).find_map(|i| violation(single, &[samples[i]]))
+        .or_else(|| pairs(n).find_map(|(i, j)| violation(pair, &[samples[i], samples[j]])))
+        .or_else(|| {
+            let triple = triple?;
+            triples(n).find_map(|(i, j, k)| violation(triple, &[samples[i], samples[j], samples[k]]))
+        })
+}
+
+fn collect<'a, I, T>(actual: &'a I) -> Vec<&'a T> where &'a I: IntoIterator<Item=&'a T> {
+    actual.into_iter().collect()
+}
+
+// Comparing a sample with itself is the point of the reflexivity check.
+#[allow(clippy::eq_op)]
+fn eq_violation<'a, T: PartialEq>(samples: &[&'a T]) -> Option<Violation<'a, T>> {
+    counterexample(samples,
+        &|s| if s[0] != s[0] { Some(("reflexive", String::from("a != a"))) } else { None },
+        &|s| match (s[0] == s[1], s[1] == s[0]) {
+            (true, false) => Some(("symmetric", String::from("a == b but b != a"))),
+            (false, true) => Some(("symmetric", String::from("b == a but a != b"))),
+            _ => None,
+        },
+        Some(&|s| if s[0] == s[1] && s[1] == s[2] && s[0] != s[2] {
+            Some(("transitive", String::from("a == b and b == c but a != c")))
+        } else {
+            None
+        }))
+}
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[allow(clippy::eq_op)]
+fn hash_violation<'a, T: Hash + PartialEq>(samples: &[&'a T]) -> Option<Violation<'a, T>> {
+    counterexample(samples,
+        &|s| {
+            let (first, second) = (hash_of(s[0]), hash_of(s[0]));
+            if s[0] == s[0] && first != second {
+                Some(("consistent with Eq", format!("a == a but hashing it twice gave {:#x} and {:#x}", first, second)))
+            } else {
+                None
+            }
+        },
+        &|s| {
+            let (a, b) = (hash_of(s[0]), hash_of(s[1]));
+            if s[0] == s[1] && a != b {
+                Some(("consistent with Eq", format!("a == b but hash(a) = {:#x} and hash(b) = {:#x}", a, b)))
+            } else {
+                None
+            }
+        },
+        None)
+}
+
+// The laws that involve two samples, which a single sample compared with itself must also obey.
+fn ord_pair_violation<T: Ord>(a: &T, b: &T, names: (&str, &str)) -> Option<(&'static str, String)> {
+    let (x, y) = names;
+    let ordering = a.cmp(b);
+    let partial = a.partial_cmp(b);
+    if partial != Some(ordering) {
+        return Some(("total", format!("partial_cmp({}, {}) = {:?} but cmp({}, {}) = {:?}", x, y, partial, x, y, ordering)))
+    }
+    let reverse = b.cmp(a);
+    if reverse != ordering.reverse() {
+        return Some(("antisymmetric", format!("cmp({}, {}) = {:?} but cmp({}, {}) = {:?}", x, y, ordering, y, x, reverse)))
+    }
+    match (ordering == Ordering::Equal, a == b) {
+        (true, false) => Some(("consistent with Eq", format!("cmp({}, {}) = Equal but {} != {}", x, y, x, y))),
+        (false, true) => Some(("consistent with Eq", format!("{} == {} but cmp({}, {}) = {:?}", x, y, x, y, ordering))),
+        _ => None,
+    }
+}
+
+fn ord_violation<'a, T: Ord>(samples: &[&'a T]) -> Option<Violation<'a, T>> {
+    counterexample(samples,
+        &|s| ord_pair_violation(s[0], s[0], ("a", "a")),
+        &|s| ord_pair_violation(s[0], s[1], ("a", "b")),
+        Some(&|s| {
+            let (ab, bc, ac) = (s[0].cmp(s[1]), s[1].cmp(s[2]), s[0].cmp(s[2]));
+            // Chaining two comparisons only constrains the outcome when they do not point in
+            // opposite directions.
+            let expected = match (ab, bc) {
+                (Ordering::Equal, other) | (other, Ordering::Equal) => Some(other),
+                (ab, bc) if ab == bc => Some(ab),
+                _ => None,
+            };
+            match expected {
+                Some(expected) if expected != ac => {
+                    Some(("transitive", format!("cmp(a, b) = {:?} and cmp(b, c) = {:?} but cmp(a, c) = {:?}", ab, bc, ac)))
+                },
+                _ => None,
+            }
+        }))
+}
+
+#[derive(Debug)]
+pub struct LawfulEq;
+
+impl<I, T: Debug + PartialEq> Matcher<I> for LawfulEq
+where for<'a> &'a I: IntoIterator<Item=&'a T> {
+    fn matches(&self, actual: &I) -> bool {
+        eq_violation(&collect(actual)).is_none()
+    }
+
+    fn failure_message(&self, actual: &I) -> String {
+        let samples = collect(actual);
+        let violation = eq_violation(&samples).map(|v| v.describe("Eq"));
+        format!("expected Eq to be lawful over {} samples, but {}",
+                samples.len(), violation.unwrap_or_else(|| String::from("no counterexample was found")))
+    }
+
+    fn negated_failure_message(&self, actual: &I) -> String {
+        format!("expected Eq not to be lawful over {} samples, but no counterexample was found", collect(actual).len())
+    }
+}
+
+#[derive(Debug)]
+pub struct HashConsistentWithEq;
+
+impl<I, T: Debug + Hash + PartialEq> Matcher<I> for HashConsistentWithEq
+where for<'a> &'a I: IntoIterator<Item=&'a T> {
+    fn matches(&self, actual: &I) -> bool {
+        hash_violation(&collect(actual)).is_none()
+    }
+
+    fn failure_message(&self, actual: &I) -> String {
+        let samples = collect(actual);
+        let violation = hash_violation(&samples).map(|v| v.describe("Hash"));
+        format!("expected Hash to be consistent with Eq over {} samples, but {}",
+                samples.len(), violation.unwrap_or_else(|| String::from("no counterexample was found")))
+    }
+
+    fn negated_failure_message(&self, actual: &I) -> String {
+        format!("expected Hash not to be consistent with Eq over {} samples, but no counterexample was found",
+                collect(actual).len())
+    }
+}
+
+#[derive(Debug)]
+pub struct LawfulOrd;
+
+impl<I, T: Debug + Ord> Matcher<I> for LawfulOrd
+where for<'a> &'a I: IntoIterator<Item=&'a T> {
+    fn matches(&self, actual: &I) -> bool {
+        ord_violation(&collect(actual)).is_none()
+    }
+
+    fn failure_message(&self, actual: &I) -> String {
+        let samples = collect(actual);
+        let violation = ord_violation(&samples).map(|v| v.describe("Ord"));
+        format!("expected Ord to be lawful over {} samples, but {}",
+                samples.len(), violation.unwrap_or_else(|| String::from("no counterexample was found")))
+    }
+
+    fn negated_failure_message(&self, actual: &I) -> String {
+        format!("expected Ord not to be lawful over {} samples, but no counterexample was found", collect(actual).len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::hash::{Hash, Hasher};
+    use dsl::Matcher;
+    use super::*;
+
+    // Equal when within 1 of each other, which is not transitive.
+    #[derive(Debug)]
+    struct Near(i32);
+
+    impl PartialEq for Near {
+        fn eq(&self, other: &Near) -> bool {
+            (self.0 - other.0).abs() <= 1
+        }
+    }
+
+    // Compares case-insensitively but hashes the raw string.
+    #[derive(Debug)]
+    struct Name(&'static str);
+
+    impl PartialEq for Name {
+        fn eq(&self, other: &Name) -> bool {
+            self.0.eq_ignore_ascii_case(other.0)
+        }
+    }
+
+    impl Hash for Name {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+        }
+    }
+
+    // Rock, paper, scissors: every ordering is antisymmetric, but none is transitive.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    enum Hand {
+        Rock,
+        Paper,
+        Scissors,
+    }
+
+    impl Ord for Hand {
+        fn cmp(&self, other: &Hand) -> Ordering {
+            match (*self, *other) {
+                (a, b) if a == b => Ordering::Equal,
+                (Hand::Rock, Hand::Scissors) | (Hand::Paper, Hand::Rock) | (Hand::Scissors, Hand::Paper) => {
+                    Ordering::Greater
+                },
+                _ => Ordering::Less,
+            }
+        }
+    }
+
+    impl PartialOrd for Hand {
+        fn partial_cmp(&self, other: &Hand) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    // Orders by length only, so distinct strings of the same length compare Equal.
+    #[derive(Debug, PartialEq, Eq)]
+    struct ByLength(&'static str);
+
+    impl Ord for ByLength {
+        fn cmp(&self, other: &ByLength) -> Ordering {
+            self.0.len().cmp(&other.0.len())
+        }
+    }
+
+    impl PartialOrd for ByLength {
+        fn partial_cmp(&self, other: &ByLength) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    #[test]
+    fn test_lawful_eq_matches() {
+        assert!(LawfulEq.matches(&vec![1, 2, 3, 2]));
+        assert!(LawfulEq.matches(&vec![Near(0), Near(5)]));
+        assert!(!LawfulEq.matches(&vec![Near(0), Near(1), Near(2)]));
+        assert!(!LawfulEq.matches(&vec![1.0, f64::NAN]));
+        assert!(LawfulEq.matches(&Vec::<f64>::new()));
+    }
+
+    #[test]
+    fn test_lawful_eq_failure_msg() {
+        let msg = LawfulEq.failure_message(&vec![Near(0), Near(1), Near(2)]);
+        assert_eq!(msg, String::from("expected Eq to be lawful over 3 samples, but Eq is not transitive: \
+                                      a = Near(0), b = Near(1), c = Near(2), where a == b and b == c but a != c"));
+
+        let msg = LawfulEq.failure_message(&vec![1, 2]);
+        assert_eq!(msg, String::from("expected Eq to be lawful over 2 samples, but no counterexample was found"));
+    }
+
+    #[test]
+    fn test_lawful_eq_reflexive_failure_msg() {
+        let msg = LawfulEq.failure_message(&[1.0, 2.0, f64::NAN]);
+        assert_eq!(msg, String::from("expected Eq to be lawful over 3 samples, but Eq is not reflexive: \
+                                      a = NaN, where a != a"));
+    }
+
+    #[test]
+    fn test_lawful_eq_negated_failure_msg() {
+        let msg = LawfulEq.negated_failure_message(&vec![1, 2]);
+        assert_eq!(msg, String::from("expected Eq not to be lawful over 2 samples, but no counterexample was found"));
+    }
+
+    #[test]
+    fn test_hash_consistent_with_eq_matches() {
+        assert!(HashConsistentWithEq.matches(&vec!["a", "b", "a"]));
+        assert!(HashConsistentWithEq.matches(&vec![Name("x"), Name("y")]));
+        assert!(!HashConsistentWithEq.matches(&vec![Name("x"), Name("X")]));
+    }
+
+    #[test]
+    fn test_hash_consistent_with_eq_failure_msg() {
+        let samples = vec![Name("alice"), Name("bob"), Name("Bob")];
+        let msg = HashConsistentWithEq.failure_message(&samples);
+        let expected = format!("expected Hash to be consistent with Eq over 3 samples, but Hash is not consistent \
+                                with Eq: a = Name(\"bob\"), b = Name(\"Bob\"), where a == b but hash(a) = {:#x} \
+                                and hash(b) = {:#x}", hash_of(&samples[1]), hash_of(&samples[2]));
+        assert_eq!(msg, expected);
+    }
+
+    #[test]
+    fn test_lawful_ord_matches() {
+        assert!(LawfulOrd.matches(&vec![3, 1, 2, 2]));
+        assert!(LawfulOrd.matches(&vec!["b", "a"]));
+        assert!(LawfulOrd.matches(&vec![Hand::Rock, Hand::Paper]));
+        assert!(!LawfulOrd.matches(&vec![Hand::Rock, Hand::Paper, Hand::Scissors]));
+        assert!(!LawfulOrd.matches(&vec![ByLength("ab"), ByLength("cd")]));
+    }
+
+    #[test]
+    fn test_lawful_ord_transitive_failure_msg() {
+        let msg = LawfulOrd.failure_message(&vec![Hand::Rock, Hand::Paper, Hand::Scissors]);
+        assert_eq!(msg, String::from("expected Ord to be lawful over 3 samples, but Ord is not transitive: \
+                                      a = Rock, b = Paper, c = Scissors, \
+                                      where cmp(a, b) = Less and cmp(b, c) = Less but cmp(a, c) = Greater"));
+    }
+
+    #[test]
+    fn test_lawful_ord_consistent_with_eq_failure_msg() {
+        let msg = LawfulOrd.failure_message(&vec![ByLength("a"), ByLength("ab"), ByLength("cd")]);
+        assert_eq!(msg, String::from("expected Ord to be lawful over 3 samples, but Ord is not consistent with Eq: \
+                                      a = ByLength(\"ab\"), b = ByLength(\"cd\"), where cmp(a, b) = Equal but a != b"));
+    }
+
+    #[test]
+    fn test_lawful_ord_negated_failure_msg() {
+        let msg = LawfulOrd.negated_failure_message(&[1, 2, 3]);
+        assert_eq!(msg, String::from("expected Ord not to be lawful over 3 samples, but no counterexample was found"));
+    }
+}
@@ -0,0 +1,392 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+use dsl::Matcher;
+
+const MAX_LISTED: usize = 16;
+
+/// Common view over map types, listing entries in a stable order.
+///
+/// Ordered maps yield their natural order, hashed maps are sorted by the
+/// Debug rendering of their keys so that failure messages are deterministic.
+pub trait MapEntries {
+    /// The key type of the map.
+    type Key: Debug;
+    /// The value type of the map.
+    type Value;
+
+    /// All entries of the map, in a stable order.
+    fn map_entries(&self) -> Vec<(&Self::Key, &Self::Value)>;
+
+    /// The value stored under the given key, using the map's own lookup.
+    fn get_value(&self, key: &Self::Key) -> Option<&Self::Value>;
+}
+
+/// Lookup of map values by any borrowed form of the key, such as `str` for `String` keys, using
+/// the map's own lookup.
+pub trait MapLookup<Q: ?Sized>: MapEntries {
+    /// The value stored under the given key.
+    fn lookup(&self, key: &Q) -> Option<&Self::Value>;
+}
+
+impl<K, V, S, Q> MapLookup<Q> for HashMap<K, V, S>
+where K: Debug + Eq + Hash + Borrow<Q>, S: BuildHasher, Q: Eq + Hash + ?Sized {
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K, V, Q> MapLookup<Q> for BTreeMap<K, V> where K: Debug + Ord + Borrow<Q>, Q: Ord + ?Sized {
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K: Debug + Eq + Hash, V, S: BuildHasher> MapEntries for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn map_entries(&self) -> Vec<(&K, &V)> {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_by_key(|&(k, _)| format!("{:?}", k));
+        entries
+    }
+
+    fn get_value(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K: Debug + Ord, V> MapEntries for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn map_entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
+    }
+
+    fn get_value(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+fn keys_of<M: MapEntries>(map: &M) -> String {
+    let entries = map.map_entries();
+    let keys: Vec<_> = entries.iter().take(MAX_LISTED).map(|&(k, _)| k).collect();
+    if entries.len() > MAX_LISTED {
+        format!("{:?} and {} more", keys, entries.len() - MAX_LISTED)
+    } else {
+        format!("{:?}", keys)
+    }
+}
+
+pub struct HaveKey<'a, E: 'a + ?Sized> {
+    expected: &'a E,
+}
+
+impl<'a, E: ?Sized> HaveKey<'a, E> {
+    pub fn new(expected: &'a E) -> HaveKey<'a, E> {
+        HaveKey {
+            expected,
+        }
+    }
+}
+
+impl<'a, E: Debug + ?Sized, M: MapLookup<E>> Matcher<M> for HaveKey<'a, E> {
+    fn matches(&self, actual: &M) -> bool {
+        actual.lookup(self.expected).is_some()
+    }
+
+    fn failure_message(&self, actual: &M) -> String {
+        format!("expected to find key {:?} in map, found keys {}",
+                self.expected, keys_of(actual))
+    }
+
+    fn negated_failure_message(&self, _actual: &M) -> String {
+        format!("expected not to find key {:?} in map", self.expected)
+    }
+}
+
+pub struct HaveKeyWithValue<'a, E: 'a + ?Sized, T> {
+    key: &'a E,
+    matcher: T,
+}
+
+impl<'a, E: ?Sized, T> HaveKeyWithValue<'a, E, T> {
+    pub fn new(key: &'a E, matcher: T) -> HaveKeyWithValue<'a, E, T> {
+        HaveKeyWithValue {
+            key,
+            matcher,
+        }
+    }
+}
+
+impl<'a, E, M, T> Matcher<M> for HaveKeyWithValue<'a, E, T>
+where E: Debug + ?Sized, M: MapLookup<E>, T: Matcher<M::Value> {
+    fn matches(&self, actual: &M) -> bool {
+        match actual.lookup(self.key) {
+            Some(value) => self.matcher.matches(value),
+            None => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &M) -> String {
+        match actual.lookup(self.key) {
+            Some(value) => {
+                format!("expected value at key {:?} to match: {}",
+                        self.key, self.matcher.failure_message(value))
+            },
+            None => {
+                format!("expected to find key {:?} in map, found keys {}",
+                        self.key, keys_of(actual))
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &M) -> String {
+        match actual.lookup(self.key) {
+            Some(value) => {
+                format!("expected value at key {:?} not to match: {}",
+                        self.key, self.matcher.negated_failure_message(value))
+            },
+            None => {
+                format!("expected not to find key {:?} in map", self.key)
+            },
+        }
+    }
+}
+
+pub struct HaveEntries<'a, K: 'a, V: 'a> {
+    expected: &'a [(K, V)],
+}
+
+impl<'a, K, V> HaveEntries<'a, K, V> {
+    pub fn new(expected: &'a [(K, V)]) -> HaveEntries<'a, K, V> {
+        HaveEntries {
+            expected,
+        }
+    }
+}
+
+impl<'a, K, V, M> Matcher<M> for HaveEntries<'a, K, V>
+where K: Debug, V: Debug + PartialEq<M::Value>, M: MapLookup<K>, M::Value: Debug {
+    fn matches(&self, actual: &M) -> bool {
+        self.expected.iter().all(|(k, v)| {
+            match actual.lookup(k) {
+                Some(value) => v.eq(value),
+                None => false,
+            }
+        })
+    }
+
+    fn failure_message(&self, actual: &M) -> String {
+        let mut missing = Vec::new();
+        let mut differing = Vec::new();
+        for (k, v) in self.expected {
+            match actual.lookup(k) {
+                Some(value) => {
+                    if !v.eq(value) {
+                        differing.push(format!("{:?}: expected {:?}, found {:?}", k, v, value));
+                    }
+                },
+                None => missing.push(k),
+            }
+        }
+
+        let mut problems = Vec::new();
+        if !missing.is_empty() {
+            problems.push(format!("missing keys {:?}", missing));
+        }
+        if !differing.is_empty() {
+            problems.push(format!("differing values [{}]", differing.join(", ")));
+        }
+        format!("expected map to contain entries {:?}, {}", self.expected, problems.join("; "))
+    }
+
+    fn negated_failure_message(&self, _actual: &M) -> String {
+        format!("expected map not to contain entries {:?}", self.expected)
+    }
+}
+
+pub struct EqualMap<'a, M: 'a> {
+    expected: &'a M,
+}
+
+impl<'a, M> EqualMap<'a, M> {
+    pub fn new(expected: &'a M) -> EqualMap<'a, M> {
+        EqualMap {
+            expected,
+        }
+    }
+}
+
+impl<'a, M> EqualMap<'a, M>
+where M: MapEntries, M::Value: Debug + PartialEq {
+    fn differences<'m>(&self, actual: &'m M) -> (Vec<&'a M::Key>, Vec<&'m M::Key>, Vec<String>) {
+        let mut missing = Vec::new();
+        let mut differing = Vec::new();
+        for (k, v) in self.expected.map_entries() {
+            match actual.get_value(k) {
+                Some(value) => {
+                    if v != value {
+                        differing.push(format!("{:?}: expected {:?}, found {:?}", k, v, value));
+                    }
+                },
+                None => missing.push(k),
+            }
+        }
+
+        let unexpected = actual.map_entries().into_iter()
+            .map(|(k, _)| k)
+            .filter(|k| self.expected.get_value(k).is_none())
+            .collect();
+
+        (missing, unexpected, differing)
+    }
+}
+
+impl<'a, M> Matcher<M> for EqualMap<'a, M>
+where M: MapEntries, M::Value: Debug + PartialEq {
+    fn matches(&self, actual: &M) -> bool {
+        let (missing, unexpected, differing) = self.differences(actual);
+        missing.is_empty() && unexpected.is_empty() && differing.is_empty()
+    }
+
+    fn failure_message(&self, actual: &M) -> String {
+        let (missing, unexpected, differing) = self.differences(actual);
+        let mut problems = Vec::new();
+        if !missing.is_empty() {
+            problems.push(format!("missing keys {:?}", missing));
+        }
+        if !unexpected.is_empty() {
+            problems.push(format!("unexpected keys {:?}", unexpected));
+        }
+        if !differing.is_empty() {
+            problems.push(format!("differing values [{}]", differing.join(", ")));
+        }
+        format!("expected maps to be equal, {}", problems.join("; "))
+    }
+
+    fn negated_failure_message(&self, actual: &M) -> String {
+        format!("expected maps not to be equal, both have keys {}", keys_of(actual))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+    use dsl::Matcher;
+    use dsl::matchers::Equals;
+    use super::*;
+
+    fn btree() -> BTreeMap<&'static str, u8> {
+        let mut map = BTreeMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map
+    }
+
+    fn hash() -> HashMap<String, u8> {
+        let mut map = HashMap::new();
+        map.insert(String::from("b"), 2);
+        map.insert(String::from("a"), 1);
+        map
+    }
+
+    #[test]
+    fn test_have_key_matches() {
+        let key = "a";
+        assert!(HaveKey::new(&key).matches(&btree()));
+        assert!(HaveKey::new(key).matches(&btree()));
+        assert!(HaveKey::new(key).matches(&hash()));
+        assert!(HaveKey::new(&String::from("b")).matches(&hash()));
+        assert!(!HaveKey::new("c").matches(&hash()));
+    }
+
+    #[test]
+    fn test_have_key_failure_msg() {
+        let msg = HaveKey::new("c").failure_message(&hash());
+        assert_eq!(msg, String::from("expected to find key \"c\" in map, found keys [\"a\", \"b\"]"));
+    }
+
+    #[test]
+    fn test_have_key_many_keys_failure_msg() {
+        let map: BTreeMap<u32, ()> = (0..100).map(|k| (k, ())).collect();
+        let msg = HaveKey::new(&100).failure_message(&map);
+        assert_eq!(msg, String::from("expected to find key 100 in map, found keys \
+                                      [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15] and 84 more"));
+    }
+
+    #[test]
+    fn test_have_key_negated_failure_msg() {
+        let key = "a";
+        let msg = HaveKey::new(&key).negated_failure_message(&btree());
+        assert_eq!(msg, String::from("expected not to find key \"a\" in map"));
+    }
+
+    #[test]
+    fn test_have_key_with_value_matches() {
+        let key = "b";
+        let value = 2;
+        assert!(HaveKeyWithValue::new(&key, Equals::new(&value)).matches(&btree()));
+    }
+
+    #[test]
+    fn test_have_key_with_value_failure_msg() {
+        let value = 3;
+        let msg = HaveKeyWithValue::new("b", Equals::new(&value)).failure_message(&hash());
+        assert_eq!(msg, String::from("expected value at key \"b\" to match: expected 3 to equal 2"));
+    }
+
+    #[test]
+    fn test_have_key_with_value_missing_key_failure_msg() {
+        let key = "c";
+        let value = 3;
+        let msg = HaveKeyWithValue::new(&key, Equals::new(&value)).failure_message(&btree());
+        assert_eq!(msg, String::from("expected to find key \"c\" in map, found keys [\"a\", \"b\"]"));
+    }
+
+    #[test]
+    fn test_have_entries_matches() {
+        let entries = [("a", 1u8)];
+        assert!(HaveEntries::new(&entries).matches(&btree()));
+        let entries = [(String::from("a"), 1u8), (String::from("b"), 2u8)];
+        assert!(HaveEntries::new(&entries).matches(&hash()));
+        let entries = [(String::from("a"), 2u8)];
+        assert!(!HaveEntries::new(&entries).matches(&hash()));
+    }
+
+    #[test]
+    fn test_have_entries_failure_msg() {
+        let entries = [("a", 5u8), ("c", 3u8)];
+        let msg = HaveEntries::new(&entries).failure_message(&btree());
+        assert_eq!(msg, String::from("expected map to contain entries [(\"a\", 5), (\"c\", 3)], \
+                                      missing keys [\"c\"]; differing values [\"a\": expected 5, found 1]"));
+    }
+
+    #[test]
+    fn test_equal_map_matches() {
+        let expected = hash();
+        assert!(EqualMap::new(&expected).matches(&hash()));
+    }
+
+    #[test]
+    fn test_equal_map_failure_msg() {
+        let mut expected = btree();
+        expected.insert("c", 3);
+        expected.insert("a", 4);
+        let mut actual = btree();
+        actual.insert("d", 4);
+        let msg = EqualMap::new(&expected).failure_message(&actual);
+        assert_eq!(msg, String::from("expected maps to be equal, missing keys [\"c\"]; \
+                                      unexpected keys [\"d\"]; differing values [\"a\": expected 4, found 1]"));
+    }
+
+    #[test]
+    fn test_equal_map_negated_failure_msg() {
+        let expected = btree();
+        let msg = EqualMap::new(&expected).negated_failure_message(&btree());
+        assert_eq!(msg, String::from("expected maps not to be equal, both have keys [\"a\", \"b\"]"));
+    }
+}
@@ -0,0 +1,166 @@
+use std::any;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use dsl::Matcher;
+
+pub struct Mapped<F, M> {
+    description: Option<&'static str>,
+    f: F,
+    matcher: M,
+}
+
+impl<F, M> Mapped<F, M> {
+    pub fn new<A, T>(description: Option<&'static str>, f: F, matcher: M) -> Mapped<F, M>
+    where F: Fn(&A) -> T {
+        Mapped {
+            description,
+            f,
+            matcher,
+        }
+    }
+
+    fn mapping(&self) -> String {
+        match self.description {
+            Some(description) => format!("when mapped by {}", description),
+            None => String::from("when mapped"),
+        }
+    }
+}
+
+impl<A: Debug, T, F, M> Matcher<A> for Mapped<F, M>
+where F: Fn(&A) -> T, M: Matcher<T> {
+    fn matches(&self, actual: &A) -> bool {
+        self.matcher.matches(&(self.f)(actual))
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} {} to match: {}",
+                actual, self.mapping(), self.matcher.failure_message(&(self.f)(actual)))
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} {} not to match: {}",
+                actual, self.mapping(), self.matcher.negated_failure_message(&(self.f)(actual)))
+    }
+}
+
+pub struct AfterParsing<T, M> {
+    parsed: PhantomData<T>,
+    matcher: M,
+}
+
+impl<T, M> AfterParsing<T, M> {
+    pub fn new(matcher: M) -> AfterParsing<T, M> {
+        AfterParsing {
+            parsed: PhantomData,
+            matcher,
+        }
+    }
+}
+
+impl<S, T, M> Matcher<S> for AfterParsing<T, M>
+where S: AsRef<str>, T: FromStr, T::Err: Debug, M: Matcher<T> {
+    fn matches(&self, actual: &S) -> bool {
+        match actual.as_ref().parse::<T>() {
+            Ok(parsed) => self.matcher.matches(&parsed),
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &S) -> String {
+        let actual = actual.as_ref();
+        match actual.parse::<T>() {
+            Ok(parsed) => {
+                format!("expected {:?} after parsing as {} to match: {}",
+                        actual, any::type_name::<T>(), self.matcher.failure_message(&parsed))
+            },
+            Err(err) => {
+                format!("expected {:?} to parse as {}, but parsing failed with {:?}",
+                        actual, any::type_name::<T>(), err)
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &S) -> String {
+        let actual = actual.as_ref();
+        match actual.parse::<T>() {
+            Ok(parsed) => {
+                format!("expected {:?} after parsing as {} not to match: {}",
+                        actual, any::type_name::<T>(), self.matcher.negated_failure_message(&parsed))
+            },
+            Err(err) => {
+                format!("expected {:?} to parse as {}, but parsing failed with {:?}",
+                        actual, any::type_name::<T>(), err)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use dsl::matchers::Equals;
+    use super::*;
+
+    #[test]
+    fn test_mapped_matches() {
+        let expected = 3;
+        let test = Mapped::new(None, |s: &&str| s.len(), Equals::new(&expected));
+        assert!(test.matches(&"abc"));
+        assert!(!test.matches(&"ab"));
+    }
+
+    #[test]
+    fn test_mapped_failure_msg() {
+        let expected = 3;
+        let test = Mapped::new(None, |s: &&str| s.len(), Equals::new(&expected));
+        let msg = test.failure_message(&"ab");
+        assert_eq!(msg, String::from("expected \"ab\" when mapped to match: expected 3 to equal 2"));
+    }
+
+    #[test]
+    fn test_mapped_description_failure_msg() {
+        let expected = String::from("abc");
+        let test = Mapped::new(Some("lowercase"), |s: &String| s.to_lowercase(), Equals::new(&expected));
+        let msg = test.failure_message(&String::from("ABD"));
+        assert_eq!(msg, String::from("expected \"ABD\" when mapped by lowercase to match: \
+                                      expected \"abc\" to equal \"abd\""));
+    }
+
+    #[test]
+    fn test_mapped_negated_failure_msg() {
+        let expected = 2;
+        let test = Mapped::new(Some("length"), |s: &&str| s.len(), Equals::new(&expected));
+        let msg = test.negated_failure_message(&"ab");
+        assert_eq!(msg, String::from("expected \"ab\" when mapped by length not to match: \
+                                      expected 2 not to equal 2"));
+    }
+
+    #[test]
+    fn test_after_parsing_matches() {
+        let expected = 42;
+        let test = AfterParsing::<i32, _>::new(Equals::new(&expected));
+        assert!(test.matches(&"42"));
+        assert!(test.matches(&String::from("42")));
+        assert!(!test.matches(&"43"));
+        assert!(!test.matches(&"forty-two"));
+    }
+
+    #[test]
+    fn test_after_parsing_failure_msg() {
+        let expected = 42;
+        let test = AfterParsing::<i32, _>::new(Equals::new(&expected));
+        let msg = test.failure_message(&"43");
+        assert_eq!(msg, String::from("expected \"43\" after parsing as i32 to match: expected 42 to equal 43"));
+    }
+
+    #[test]
+    fn test_after_parsing_parse_failure_msg() {
+        let expected = 42;
+        let test = AfterParsing::<u8, _>::new(Equals::new(&expected));
+        let msg = test.failure_message(&"-1");
+        assert_eq!(msg, String::from("expected \"-1\" to parse as u8, \
+                                      but parsing failed with ParseIntError { kind: InvalidDigit }"));
+    }
+}
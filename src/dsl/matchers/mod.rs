@@ -4,11 +4,80 @@ pub use self::equals::*;
 pub mod contain;
 pub use self::contain::*;
 
+pub mod bytes;
+pub use self::bytes::*;
+
+pub mod sorted;
+pub use self::sorted::*;
+
 pub mod option;
 pub use self::option::*;
 
 pub mod result;
 pub use self::result::*;
 
+pub mod error;
+pub use self::error::*;
+
+pub mod io_error;
+pub use self::io_error::*;
+
 pub mod boolean;
 pub use self::boolean::*;
+
+pub mod map;
+pub use self::map::*;
+
+pub mod set;
+pub use self::set::*;
+
+pub mod panic;
+pub use self::panic::*;
+
+#[macro_use]
+pub mod pattern;
+pub use self::pattern::*;
+
+#[macro_use]
+pub mod field;
+pub use self::field::*;
+
+pub mod mapped;
+pub use self::mapped::*;
+
+pub mod path;
+pub use self::path::*;
+
+pub mod json;
+pub use self::json::*;
+
+#[macro_use]
+pub mod snapshot;
+pub use self::snapshot::*;
+
+pub mod golden;
+pub use self::golden::*;
+
+pub mod duration;
+pub use self::duration::*;
+
+pub mod range;
+pub use self::range::*;
+
+pub mod numeric;
+pub use self::numeric::*;
+
+pub mod close_to;
+pub use self::close_to::*;
+
+pub mod statistics;
+pub use self::statistics::*;
+
+pub mod render;
+pub use self::render::*;
+
+pub mod round_trip;
+pub use self::round_trip::*;
+
+pub mod laws;
+pub use self::laws::*;
@@ -0,0 +1,312 @@
+use std::fmt::Debug;
+use dsl::Matcher;
+
+/// Integer and float primitives the numeric matchers can inspect.
+pub trait Number: Copy + Debug + PartialOrd {
+    /// Zero of this type.
+    const ZERO: Self;
+
+    /// The number as a float, rounding large integers to the nearest representable value.
+    fn to_f64(self) -> f64;
+
+    /// Whether the number is even. Floats must be integral to be even.
+    fn is_even(self) -> bool;
+
+    /// Whether the number is odd. Floats must be integral to be odd.
+    fn is_odd(self) -> bool;
+
+    /// Whether the number is an exact multiple of `n`. Only zero is a multiple of zero.
+    fn is_multiple_of(self, n: Self) -> bool;
+
+    /// Whether the number is an integral power of two; for floats this includes `0.5`, `0.25`...
+    fn is_power_of_two(self) -> bool;
+
+    /// Whether the number is neither infinite nor NaN.
+    fn is_finite(self) -> bool {
+        true
+    }
+
+    /// Whether the number is NaN.
+    fn is_nan(self) -> bool {
+        false
+    }
+
+    /// Whether the number is positive or negative infinity.
+    fn is_infinite(self) -> bool {
+        false
+    }
+}
+
+macro_rules! int_number_impls {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: $t = 0;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn is_even(self) -> bool {
+                    self % 2 == 0
+                }
+
+                fn is_odd(self) -> bool {
+                    self % 2 != 0
+                }
+
+                fn is_multiple_of(self, n: $t) -> bool {
+                    if n == 0 {
+                        return self == 0
+                    }
+                    // The only overflowing remainder is MIN % -1, and MIN is a multiple of -1.
+                    self.checked_rem(n).is_none_or(|rem| rem == 0)
+                }
+
+                fn is_power_of_two(self) -> bool {
+                    self > 0 && (self as $unsigned).is_power_of_two()
+                }
+            }
+        )*
+    };
+}
+
+int_number_impls! {
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+}
+
+macro_rules! float_number_impls {
+    ($($t:ident, $mantissa_bits:expr);*) => {
+        $(
+            impl Number for $t {
+                const ZERO: $t = 0.0;
+
+                fn to_f64(self) -> f64 {
+                    f64::from(self)
+                }
+
+                fn is_even(self) -> bool {
+                    self.is_finite() && self % 2.0 == 0.0
+                }
+
+                fn is_odd(self) -> bool {
+                    self.is_finite() && self.abs() % 2.0 == 1.0
+                }
+
+                fn is_multiple_of(self, n: $t) -> bool {
+                    if n == 0.0 {
+                        return self == 0.0
+                    }
+                    self.is_finite() && self % n == 0.0
+                }
+
+                fn is_power_of_two(self) -> bool {
+                    if !(self > 0.0 && self.is_finite()) {
+                        return false
+                    }
+                    let bits = self.to_bits();
+                    let mantissa = bits & ((1 << $mantissa_bits) - 1);
+                    if bits >> $mantissa_bits == 0 {
+                        // Subnormal numbers carry their power of two in the mantissa.
+                        mantissa.is_power_of_two()
+                    } else {
+                        mantissa == 0
+                    }
+                }
+
+                fn is_finite(self) -> bool {
+                    $t::is_finite(self)
+                }
+
+                fn is_nan(self) -> bool {
+                    $t::is_nan(self)
+                }
+
+                fn is_infinite(self) -> bool {
+                    $t::is_infinite(self)
+                }
+            }
+        )*
+    };
+}
+
+float_number_impls!(f32, 23; f64, 52);
+
+#[derive(Debug)]
+pub enum NumericMatcher<T> {
+    Positive,
+    Negative,
+    Zero,
+    Even,
+    Odd,
+    MultipleOf(T),
+    PowerOfTwo,
+    Finite,
+    Nan,
+    Infinite,
+}
+
+impl<T: Number> NumericMatcher<T> {
+    // What a matching number is, and what a number that does not match is.
+    fn descriptions(&self) -> (String, String) {
+        let (positive, negative) = match *self {
+            NumericMatcher::Positive => ("a positive number", "a non-positive number"),
+            NumericMatcher::Negative => ("a negative number", "a non-negative number"),
+            NumericMatcher::Zero => ("zero", "a non-zero number"),
+            NumericMatcher::Even => ("an even number", "a number that is not even"),
+            NumericMatcher::Odd => ("an odd number", "a number that is not odd"),
+            NumericMatcher::MultipleOf(n) => {
+                return (format!("a multiple of {:?}", n), format!("a number that is not a multiple of {:?}", n))
+            },
+            NumericMatcher::PowerOfTwo => ("a power of two", "a number that is not a power of two"),
+            NumericMatcher::Finite => ("a finite number", "an infinite or NaN number"),
+            NumericMatcher::Nan => ("NaN", "a number other than NaN"),
+            NumericMatcher::Infinite => ("an infinite number", "a finite or NaN number"),
+        };
+        (positive.to_owned(), negative.to_owned())
+    }
+}
+
+impl<T: Number> Matcher<T> for NumericMatcher<T> {
+    fn matches(&self, actual: &T) -> bool {
+        let actual = *actual;
+        match *self {
+            NumericMatcher::Positive => actual > T::ZERO,
+            NumericMatcher::Negative => actual < T::ZERO,
+            NumericMatcher::Zero => actual == T::ZERO,
+            NumericMatcher::Even => actual.is_even(),
+            NumericMatcher::Odd => actual.is_odd(),
+            NumericMatcher::MultipleOf(n) => actual.is_multiple_of(n),
+            NumericMatcher::PowerOfTwo => actual.is_power_of_two(),
+            NumericMatcher::Finite => actual.is_finite(),
+            NumericMatcher::Nan => actual.is_nan(),
+            NumericMatcher::Infinite => actual.is_infinite(),
+        }
+    }
+
+    fn failure_message(&self, actual: &T) -> String {
+        format!("expected {}, found {:?}", self.descriptions().0, actual)
+    }
+
+    fn negated_failure_message(&self, actual: &T) -> String {
+        format!("expected {}, found {:?}", self.descriptions().1, actual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use super::*;
+
+    #[test]
+    fn test_sign_matches() {
+        assert!(NumericMatcher::Positive.matches(&3));
+        assert!(!NumericMatcher::Positive.matches(&0u8));
+        assert!(NumericMatcher::Negative.matches(&-0.5));
+        assert!(!NumericMatcher::Negative.matches(&-0.0));
+        assert!(!NumericMatcher::Negative.matches(&f64::NAN));
+        assert!(NumericMatcher::Zero.matches(&-0.0f32));
+        assert!(!NumericMatcher::Zero.matches(&1i64));
+    }
+
+    #[test]
+    fn test_sign_failure_msg() {
+        let msg = NumericMatcher::Positive.failure_message(&-3);
+        assert_eq!(msg, String::from("expected a positive number, found -3"));
+    }
+
+    #[test]
+    fn test_sign_negated_failure_msg() {
+        let msg = NumericMatcher::Positive.negated_failure_message(&5);
+        assert_eq!(msg, String::from("expected a non-positive number, found 5"));
+
+        let msg = NumericMatcher::Zero.negated_failure_message(&0.0);
+        assert_eq!(msg, String::from("expected a non-zero number, found 0.0"));
+    }
+
+    #[test]
+    fn test_parity_matches() {
+        assert!(NumericMatcher::Even.matches(&-4));
+        assert!(NumericMatcher::Odd.matches(&-3));
+        assert!(!NumericMatcher::Odd.matches(&0u32));
+        assert!(NumericMatcher::Even.matches(&4.0));
+        assert!(NumericMatcher::Odd.matches(&-3.0));
+        assert!(!NumericMatcher::Even.matches(&2.5));
+        assert!(!NumericMatcher::Odd.matches(&2.5));
+        assert!(!NumericMatcher::Even.matches(&f64::INFINITY));
+    }
+
+    #[test]
+    fn test_parity_failure_msg() {
+        let msg = NumericMatcher::Even.failure_message(&7);
+        assert_eq!(msg, String::from("expected an even number, found 7"));
+
+        let msg = NumericMatcher::Odd.negated_failure_message(&7);
+        assert_eq!(msg, String::from("expected a number that is not odd, found 7"));
+    }
+
+    #[test]
+    fn test_multiple_of_matches() {
+        assert!(NumericMatcher::MultipleOf(3).matches(&-9));
+        assert!(!NumericMatcher::MultipleOf(3).matches(&10));
+        assert!(NumericMatcher::MultipleOf(0).matches(&0));
+        assert!(!NumericMatcher::MultipleOf(0).matches(&1));
+        assert!(NumericMatcher::MultipleOf(-1).matches(&i32::MIN));
+        assert!(NumericMatcher::MultipleOf(0.25).matches(&1.5));
+        assert!(!NumericMatcher::MultipleOf(0.25).matches(&1.6));
+    }
+
+    #[test]
+    fn test_multiple_of_failure_msg() {
+        let msg = NumericMatcher::MultipleOf(4u64).failure_message(&10);
+        assert_eq!(msg, String::from("expected a multiple of 4, found 10"));
+
+        let msg = NumericMatcher::MultipleOf(4u64).negated_failure_message(&12);
+        assert_eq!(msg, String::from("expected a number that is not a multiple of 4, found 12"));
+    }
+
+    #[test]
+    fn test_power_of_two_matches() {
+        assert!(NumericMatcher::PowerOfTwo.matches(&64u8));
+        assert!(NumericMatcher::PowerOfTwo.matches(&1i32));
+        assert!(!NumericMatcher::PowerOfTwo.matches(&0i32));
+        assert!(!NumericMatcher::PowerOfTwo.matches(&i8::MIN));
+        assert!(!NumericMatcher::PowerOfTwo.matches(&12usize));
+        assert!(NumericMatcher::PowerOfTwo.matches(&0.125f32));
+        assert!(NumericMatcher::PowerOfTwo.matches(&1024.0));
+        assert!(NumericMatcher::PowerOfTwo.matches(&f64::from_bits(1)));
+        assert!(!NumericMatcher::PowerOfTwo.matches(&-2.0));
+        assert!(!NumericMatcher::PowerOfTwo.matches(&3.0));
+    }
+
+    #[test]
+    fn test_power_of_two_failure_msg() {
+        let msg = NumericMatcher::PowerOfTwo.failure_message(&12);
+        assert_eq!(msg, String::from("expected a power of two, found 12"));
+    }
+
+    #[test]
+    fn test_finiteness_matches() {
+        assert!(NumericMatcher::Finite.matches(&1));
+        assert!(NumericMatcher::Finite.matches(&1.0));
+        assert!(!NumericMatcher::Finite.matches(&f64::NAN));
+        assert!(NumericMatcher::Nan.matches(&f32::NAN));
+        assert!(!NumericMatcher::Nan.matches(&1u8));
+        assert!(NumericMatcher::Infinite.matches(&f64::NEG_INFINITY));
+        assert!(!NumericMatcher::Infinite.matches(&f64::NAN));
+        assert!(!NumericMatcher::Infinite.matches(&i64::MAX));
+    }
+
+    #[test]
+    fn test_finiteness_failure_msg() {
+        let msg = NumericMatcher::Finite.failure_message(&f64::INFINITY);
+        assert_eq!(msg, String::from("expected a finite number, found inf"));
+
+        let msg = NumericMatcher::Nan.negated_failure_message(&f64::NAN);
+        assert_eq!(msg, String::from("expected a number other than NaN, found NaN"));
+
+        let msg = NumericMatcher::Infinite.negated_failure_message(&f32::NEG_INFINITY);
+        assert_eq!(msg, String::from("expected a finite or NaN number, found -inf"));
+    }
+}
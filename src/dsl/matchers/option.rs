@@ -37,9 +37,51 @@ impl<E: Debug> Matcher<Option<E>> for OptionMatcher {
     }
 }
 
+pub struct SomeWith<M> {
+    matcher: M,
+}
+
+impl<M> SomeWith<M> {
+    pub fn new(matcher: M) -> SomeWith<M> {
+        SomeWith {
+            matcher,
+        }
+    }
+}
+
+impl<E: Debug, M: Matcher<E>> Matcher<Option<E>> for SomeWith<M> {
+    fn matches(&self, actual: &Option<E>) -> bool {
+        match *actual {
+            Some(ref value) => self.matcher.matches(value),
+            None => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &Option<E>) -> String {
+        match *actual {
+            Some(ref value) => {
+                format!("expected Some variant whose value matches: {}, found {:?}",
+                        self.matcher.failure_message(value), actual)
+            },
+            None => format!("expected Some variant, found {:?}", actual),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &Option<E>) -> String {
+        match *actual {
+            Some(ref value) => {
+                format!("expected Some variant whose value does not match: {}, found {:?}",
+                        self.matcher.negated_failure_message(value), actual)
+            },
+            None => format!("expected Some variant, found {:?}", actual),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use dsl::Matcher;
+    use dsl::matchers::Equals;
     use super::*;
 
     #[test]
@@ -93,4 +135,39 @@ mod tests {
         let msg = test.negated_failure_message(&None as &Option<u8>);
         assert_eq!(msg, String::from("expected Some variant, found None"));
     }
+
+    #[test]
+    fn test_some_with_matches() {
+        let expected = 1;
+        let test = SomeWith::new(Equals::new(&expected));
+        assert!(test.matches(&Some(1)));
+        assert!(!test.matches(&Some(2)));
+        assert!(!test.matches(&None));
+    }
+
+    #[test]
+    fn test_some_with_failure_msg() {
+        let expected = 1;
+        let test = SomeWith::new(Equals::new(&expected));
+        let msg = test.failure_message(&Some(2));
+        assert_eq!(msg, String::from("expected Some variant whose value matches: \
+                                      expected 1 to equal 2, found Some(2)"));
+    }
+
+    #[test]
+    fn test_some_with_none_failure_msg() {
+        let expected = 1;
+        let test = SomeWith::new(Equals::new(&expected));
+        let msg = test.failure_message(&None);
+        assert_eq!(msg, String::from("expected Some variant, found None"));
+    }
+
+    #[test]
+    fn test_some_with_negated_failure_msg() {
+        let expected = 1;
+        let test = SomeWith::new(Equals::new(&expected));
+        let msg = test.negated_failure_message(&Some(1));
+        assert_eq!(msg, String::from("expected Some variant whose value does not match: \
+                                      expected 1 not to equal 1, found Some(1)"));
+    }
 }
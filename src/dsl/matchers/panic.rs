@@ -0,0 +1,283 @@
+use std::any::{self, Any};
+use std::cell::Cell;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use dsl::Matcher;
+
+thread_local!(static SUPPRESS_PANIC_OUTPUT: Cell<bool> = const { Cell::new(false) });
+
+static INSTALL_HOOK: Once = Once::new();
+
+// Installs a panic hook that stays quiet while the current thread is probing a closure, and
+// defers to the previously installed hook otherwise. The hook is process-wide, so it is only
+// swapped once to avoid racing with tests running on other threads.
+fn install_quiet_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SUPPRESS_PANIC_OUTPUT.with(|suppress| suppress.get()) {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// The outcome of running a closure that may panic.
+pub struct PanicOutcome {
+    result: Result<(), Box<dyn Any + Send>>,
+}
+
+impl PanicOutcome {
+    /// Runs the given closure, catching any panic without printing it.
+    pub fn catch<F, R>(f: F) -> PanicOutcome where F: FnOnce() -> R {
+        install_quiet_hook();
+        let suppressed = SUPPRESS_PANIC_OUTPUT.with(|suppress| suppress.replace(true));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| { f(); }));
+        SUPPRESS_PANIC_OUTPUT.with(|suppress| suppress.set(suppressed));
+
+        PanicOutcome {
+            result,
+        }
+    }
+
+    /// Whether the closure panicked.
+    pub fn panicked(&self) -> bool {
+        self.result.is_err()
+    }
+
+    /// The panic payload, if the closure panicked.
+    pub fn payload(&self) -> Option<&(dyn Any + Send)> {
+        match self.result {
+            Ok(()) => None,
+            Err(ref payload) => Some(&**payload),
+        }
+    }
+
+    /// The panic message, if the closure panicked with a string payload.
+    pub fn message(&self) -> Option<String> {
+        self.payload().and_then(|payload| {
+            if let Some(msg) = payload.downcast_ref::<&str>() {
+                Some(String::from(*msg))
+            } else {
+                payload.downcast_ref::<String>().cloned()
+            }
+        })
+    }
+}
+
+impl Debug for PanicOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.result {
+            Ok(()) => write!(f, "returned normally"),
+            Err(_) => match self.message() {
+                Some(msg) => write!(f, "panicked with message {:?}", msg),
+                None => write!(f, "panicked with a non-string payload"),
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PanicMatcher {
+    Panic,
+    NotPanic,
+}
+
+impl Matcher<PanicOutcome> for PanicMatcher {
+    fn matches(&self, actual: &PanicOutcome) -> bool {
+        match *self {
+            PanicMatcher::Panic => actual.panicked(),
+            PanicMatcher::NotPanic => !actual.panicked(),
+        }
+    }
+
+    fn failure_message(&self, actual: &PanicOutcome) -> String {
+        match *self {
+            PanicMatcher::Panic => {
+                format!("expected closure to panic, but it {:?}", actual)
+            },
+            PanicMatcher::NotPanic => {
+                format!("expected closure not to panic, but it {:?}", actual)
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &PanicOutcome) -> String {
+        match *self {
+            PanicMatcher::Panic => {
+                format!("expected closure not to panic, but it {:?}", actual)
+            },
+            PanicMatcher::NotPanic => {
+                format!("expected closure to panic, but it {:?}", actual)
+            },
+        }
+    }
+}
+
+pub struct PanicWithMessage<M> {
+    matcher: M,
+}
+
+impl<M> PanicWithMessage<M> {
+    pub fn new(matcher: M) -> PanicWithMessage<M> {
+        PanicWithMessage {
+            matcher,
+        }
+    }
+}
+
+impl<M: Matcher<String>> Matcher<PanicOutcome> for PanicWithMessage<M> {
+    fn matches(&self, actual: &PanicOutcome) -> bool {
+        match actual.message() {
+            Some(msg) => self.matcher.matches(&msg),
+            None => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &PanicOutcome) -> String {
+        match actual.message() {
+            Some(msg) => {
+                format!("expected panic message to match: {}", self.matcher.failure_message(&msg))
+            },
+            None => format!("expected closure to panic with a message, but it {:?}", actual),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &PanicOutcome) -> String {
+        match actual.message() {
+            Some(msg) => {
+                format!("expected panic message not to match: {}",
+                        self.matcher.negated_failure_message(&msg))
+            },
+            None => format!("expected closure not to panic with a message, but it {:?}", actual),
+        }
+    }
+}
+
+pub struct PanicWithPayload<T> {
+    payload: PhantomData<T>,
+}
+
+impl<T> PanicWithPayload<T> {
+    pub fn new() -> PanicWithPayload<T> {
+        PanicWithPayload {
+            payload: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for PanicWithPayload<T> {
+    fn default() -> PanicWithPayload<T> {
+        PanicWithPayload::new()
+    }
+}
+
+impl<T: Any> Matcher<PanicOutcome> for PanicWithPayload<T> {
+    fn matches(&self, actual: &PanicOutcome) -> bool {
+        match actual.payload() {
+            Some(payload) => payload.is::<T>(),
+            None => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &PanicOutcome) -> String {
+        format!("expected closure to panic with a payload of type {}, but it {:?}",
+                any::type_name::<T>(), actual)
+    }
+
+    fn negated_failure_message(&self, actual: &PanicOutcome) -> String {
+        format!("expected closure not to panic with a payload of type {}, but it {:?}",
+                any::type_name::<T>(), actual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use dsl::matchers::Equals;
+    use super::*;
+
+    #[derive(Debug)]
+    struct Payload;
+
+    fn returns() -> PanicOutcome {
+        PanicOutcome::catch(|| 1)
+    }
+
+    fn panics() -> PanicOutcome {
+        PanicOutcome::catch(|| panic!("boom {}", 1))
+    }
+
+    fn panics_with_payload() -> PanicOutcome {
+        PanicOutcome::catch(|| panic::panic_any(Payload))
+    }
+
+    #[test]
+    fn test_outcome_message() {
+        assert_eq!(returns().message(), None);
+        assert_eq!(panics().message(), Some(String::from("boom 1")));
+        assert_eq!(PanicOutcome::catch(|| panic!("static")).message(), Some(String::from("static")));
+        assert_eq!(panics_with_payload().message(), None);
+    }
+
+    #[test]
+    fn test_panic_matches() {
+        let test = PanicMatcher::Panic;
+        assert!(test.matches(&panics()));
+        assert!(!test.matches(&returns()));
+    }
+
+    #[test]
+    fn test_panic_failure_msg() {
+        let test = PanicMatcher::Panic;
+        let msg = test.failure_message(&returns());
+        assert_eq!(msg, String::from("expected closure to panic, but it returned normally"));
+    }
+
+    #[test]
+    fn test_panic_negated_failure_msg() {
+        let test = PanicMatcher::Panic;
+        let msg = test.negated_failure_message(&panics());
+        assert_eq!(msg, String::from("expected closure not to panic, but it panicked with message \"boom 1\""));
+    }
+
+    #[test]
+    fn test_not_panic_failure_msg() {
+        let test = PanicMatcher::NotPanic;
+        let msg = test.failure_message(&panics_with_payload());
+        assert_eq!(msg, String::from("expected closure not to panic, but it panicked with a non-string payload"));
+    }
+
+    #[test]
+    fn test_panic_with_message_matches() {
+        let expected = String::from("boom 1");
+        let test = PanicWithMessage::new(Equals::new(&expected));
+        assert!(test.matches(&panics()));
+        assert!(!test.matches(&returns()));
+    }
+
+    #[test]
+    fn test_panic_with_message_failure_msg() {
+        let expected = String::from("bang");
+        let test = PanicWithMessage::new(Equals::new(&expected));
+        let msg = test.failure_message(&panics());
+        assert_eq!(msg, String::from("expected panic message to match: expected \"bang\" to equal \"boom 1\""));
+    }
+
+    #[test]
+    fn test_panic_with_payload_matches() {
+        let test = PanicWithPayload::<Payload>::new();
+        assert!(test.matches(&panics_with_payload()));
+        assert!(!test.matches(&panics()));
+    }
+
+    #[test]
+    fn test_panic_with_payload_failure_msg() {
+        let test = PanicWithPayload::<u8>::new();
+        let msg = test.failure_message(&panics());
+        assert_eq!(msg, String::from("expected closure to panic with a payload of type u8, \
+                                      but it panicked with message \"boom 1\""));
+    }
+}
@@ -0,0 +1,390 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use dsl::Matcher;
+
+// What is found at the given path, without following symlinks.
+fn describe(path: &Path) -> String {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) => {
+            let file_type = metadata.file_type();
+            if file_type.is_symlink() {
+                String::from("a symlink")
+            } else if file_type.is_dir() {
+                String::from("a directory")
+            } else if file_type.is_file() {
+                String::from("a file")
+            } else {
+                String::from("a special file")
+            }
+        },
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => String::from("nothing"),
+        Err(err) => format!("an inaccessible path ({})", err),
+    }
+}
+
+#[derive(Debug)]
+pub enum PathMatcher {
+    Exist,
+    File,
+    Dir,
+    Symlink,
+}
+
+impl PathMatcher {
+    fn expectation(&self) -> &'static str {
+        match *self {
+            PathMatcher::Exist => "exist",
+            PathMatcher::File => "be a file",
+            PathMatcher::Dir => "be a directory",
+            PathMatcher::Symlink => "be a symlink",
+        }
+    }
+}
+
+impl<P: AsRef<Path>> Matcher<P> for PathMatcher {
+    fn matches(&self, actual: &P) -> bool {
+        let path = actual.as_ref();
+        match *self {
+            PathMatcher::Exist => path.exists(),
+            PathMatcher::File => path.is_file(),
+            PathMatcher::Dir => path.is_dir(),
+            PathMatcher::Symlink => {
+                fs::symlink_metadata(path).map(|m| m.file_type().is_symlink()).unwrap_or(false)
+            },
+        }
+    }
+
+    fn failure_message(&self, actual: &P) -> String {
+        let path = actual.as_ref();
+        format!("expected path {:?} to {}, found {}", path, self.expectation(), describe(path))
+    }
+
+    fn negated_failure_message(&self, actual: &P) -> String {
+        let path = actual.as_ref();
+        format!("expected path {:?} not to {}, found {}", path, self.expectation(), describe(path))
+    }
+}
+
+pub struct HaveContents<M> {
+    matcher: M,
+}
+
+impl<M> HaveContents<M> {
+    pub fn new(matcher: M) -> HaveContents<M> {
+        HaveContents {
+            matcher,
+        }
+    }
+}
+
+impl<P: AsRef<Path>, M: Matcher<String>> Matcher<P> for HaveContents<M> {
+    fn matches(&self, actual: &P) -> bool {
+        match fs::read_to_string(actual) {
+            Ok(contents) => self.matcher.matches(&contents),
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &P) -> String {
+        let path = actual.as_ref();
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                format!("expected contents of {:?} to match: {}",
+                        path, self.matcher.failure_message(&contents))
+            },
+            Err(err) => {
+                format!("expected contents of {:?} to match, but reading failed: {}", path, err)
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &P) -> String {
+        let path = actual.as_ref();
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                format!("expected contents of {:?} not to match: {}",
+                        path, self.matcher.negated_failure_message(&contents))
+            },
+            Err(err) => {
+                format!("expected contents of {:?} not to match, but reading failed: {}", path, err)
+            },
+        }
+    }
+}
+
+pub struct HaveSize<M> {
+    matcher: M,
+}
+
+impl<M> HaveSize<M> {
+    pub fn new(matcher: M) -> HaveSize<M> {
+        HaveSize {
+            matcher,
+        }
+    }
+}
+
+impl<P: AsRef<Path>, M: Matcher<u64>> Matcher<P> for HaveSize<M> {
+    fn matches(&self, actual: &P) -> bool {
+        match fs::metadata(actual) {
+            Ok(metadata) => self.matcher.matches(&metadata.len()),
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &P) -> String {
+        let path = actual.as_ref();
+        match fs::metadata(path) {
+            Ok(metadata) => {
+                format!("expected size of {:?} to match: {}",
+                        path, self.matcher.failure_message(&metadata.len()))
+            },
+            Err(_) => format!("expected size of {:?} to match, found {}", path, describe(path)),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &P) -> String {
+        let path = actual.as_ref();
+        match fs::metadata(path) {
+            Ok(metadata) => {
+                format!("expected size of {:?} not to match: {}",
+                        path, self.matcher.negated_failure_message(&metadata.len()))
+            },
+            Err(_) => format!("expected size of {:?} not to match, found {}", path, describe(path)),
+        }
+    }
+}
+
+#[cfg(unix)]
+#[derive(Debug)]
+pub struct HavePermissions {
+    mode: u32,
+}
+
+#[cfg(unix)]
+impl HavePermissions {
+    pub fn new(mode: u32) -> HavePermissions {
+        HavePermissions {
+            mode,
+        }
+    }
+
+    fn mode_of(&self, path: &Path) -> Option<u32> {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::metadata(path).ok().map(|metadata| metadata.permissions().mode() & 0o7777)
+    }
+
+    fn found(&self, path: &Path) -> String {
+        match self.mode_of(path) {
+            Some(mode) => format!("{:#o}", mode),
+            None => describe(path),
+        }
+    }
+}
+
+#[cfg(unix)]
+impl<P: AsRef<Path>> Matcher<P> for HavePermissions {
+    fn matches(&self, actual: &P) -> bool {
+        self.mode_of(actual.as_ref()) == Some(self.mode)
+    }
+
+    fn failure_message(&self, actual: &P) -> String {
+        let path = actual.as_ref();
+        format!("expected path {:?} to have permissions {:#o}, found {}",
+                path, self.mode, self.found(path))
+    }
+
+    fn negated_failure_message(&self, actual: &P) -> String {
+        let path = actual.as_ref();
+        format!("expected path {:?} not to have permissions {:#o}, found {}",
+                path, self.mode, self.found(path))
+    }
+}
+
+pub struct ContainEntries<'a, E: 'a> {
+    expected: &'a [E],
+}
+
+impl<'a, E> ContainEntries<'a, E> {
+    pub fn new(expected: &'a [E]) -> ContainEntries<'a, E> {
+        ContainEntries {
+            expected,
+        }
+    }
+
+    fn missing(&self, dir: &Path) -> Vec<&'a Path> where E: AsRef<Path> {
+        self.expected.iter()
+            .map(|entry| entry.as_ref())
+            .filter(|entry| fs::symlink_metadata(dir.join(entry)).is_err())
+            .collect()
+    }
+}
+
+impl<'a, P: AsRef<Path>, E: AsRef<Path>> Matcher<P> for ContainEntries<'a, E> {
+    fn matches(&self, actual: &P) -> bool {
+        let dir = actual.as_ref();
+        dir.is_dir() && self.missing(dir).is_empty()
+    }
+
+    fn failure_message(&self, actual: &P) -> String {
+        let dir = actual.as_ref();
+        if dir.is_dir() {
+            format!("expected directory {:?} to contain entries, missing {:?}",
+                    dir, self.missing(dir))
+        } else {
+            format!("expected path {:?} to be a directory with entries, found {}",
+                    dir, describe(dir))
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &P) -> String {
+        let entries: Vec<&Path> = self.expected.iter().map(|entry| entry.as_ref()).collect();
+        format!("expected directory {:?} not to contain entries {:?}", actual.as_ref(), entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use dsl::Matcher;
+    use dsl::matchers::Equals;
+    use super::*;
+
+    // Removes its scratch directory when the test ends, whether or not it passed.
+    struct ScratchDir(PathBuf);
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // A fresh directory containing a file `file.txt` with the contents "hello", and the guard
+    // that removes it.
+    fn scratch(name: &str) -> (ScratchDir, PathBuf) {
+        let dir = env::temp_dir().join(format!("ferrous-path-{}-{}", ::std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("file.txt"), "hello").unwrap();
+        (ScratchDir(dir.clone()), dir)
+    }
+
+    #[test]
+    fn test_exist_matches() {
+        let (_guard, dir) = scratch("exist");
+        assert!(PathMatcher::Exist.matches(&dir));
+        assert!(PathMatcher::Exist.matches(&dir.join("file.txt")));
+        assert!(!PathMatcher::Exist.matches(&dir.join("missing")));
+        assert!(PathMatcher::Exist.matches(&dir.to_str().unwrap()));
+    }
+
+    #[test]
+    fn test_exist_failure_msg() {
+        let msg = PathMatcher::Exist.failure_message(&"/nonexistent/ferrous");
+        assert_eq!(msg, String::from("expected path \"/nonexistent/ferrous\" to exist, found nothing"));
+    }
+
+    #[test]
+    fn test_file_matches() {
+        let (_guard, dir) = scratch("file");
+        assert!(PathMatcher::File.matches(&dir.join("file.txt")));
+        assert!(!PathMatcher::File.matches(&dir));
+    }
+
+    #[test]
+    fn test_file_failure_msg() {
+        let (_guard, dir) = scratch("file_msg");
+        let msg = PathMatcher::File.failure_message(&dir);
+        assert_eq!(msg, format!("expected path {:?} to be a file, found a directory", dir));
+    }
+
+    #[test]
+    fn test_dir_negated_failure_msg() {
+        let (_guard, dir) = scratch("dir_msg");
+        let msg = PathMatcher::Dir.negated_failure_message(&dir);
+        assert_eq!(msg, format!("expected path {:?} not to be a directory, found a directory", dir));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_matches() {
+        let (_guard, dir) = scratch("symlink");
+        ::std::os::unix::fs::symlink(dir.join("file.txt"), dir.join("link")).unwrap();
+        assert!(PathMatcher::Symlink.matches(&dir.join("link")));
+        assert!(!PathMatcher::Symlink.matches(&dir.join("file.txt")));
+    }
+
+    #[test]
+    fn test_have_contents_matches() {
+        let (_guard, dir) = scratch("contents");
+        let expected = String::from("hello");
+        assert!(HaveContents::new(Equals::new(&expected)).matches(&dir.join("file.txt")));
+        assert!(!HaveContents::new(Equals::new(&expected)).matches(&dir.join("missing")));
+    }
+
+    #[test]
+    fn test_have_contents_failure_msg() {
+        let (_guard, dir) = scratch("contents_msg");
+        let expected = String::from("bye");
+        let path = dir.join("file.txt");
+        let msg = HaveContents::new(Equals::new(&expected)).failure_message(&path);
+        assert_eq!(msg, format!("expected contents of {:?} to match: expected \"bye\" to equal \"hello\"", path));
+    }
+
+    #[test]
+    fn test_have_size_matches() {
+        let (_guard, dir) = scratch("size");
+        let expected = 5;
+        assert!(HaveSize::new(Equals::new(&expected)).matches(&dir.join("file.txt")));
+    }
+
+    #[test]
+    fn test_have_size_failure_msg() {
+        let (_guard, dir) = scratch("size_msg");
+        let expected = 4;
+        let path = dir.join("file.txt");
+        let msg = HaveSize::new(Equals::new(&expected)).failure_message(&path);
+        assert_eq!(msg, format!("expected size of {:?} to match: expected 4 to equal 5", path));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_have_permissions_matches() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (_guard, dir) = scratch("permissions");
+        let path = dir.join("file.txt");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+        assert!(HavePermissions::new(0o640).matches(&path));
+        assert!(!HavePermissions::new(0o644).matches(&path));
+
+        let msg = HavePermissions::new(0o644).failure_message(&path);
+        assert_eq!(msg, format!("expected path {:?} to have permissions 0o644, found 0o640", path));
+    }
+
+    #[test]
+    fn test_contain_entries_matches() {
+        let (_guard, dir) = scratch("entries");
+        assert!(ContainEntries::new(&["file.txt"]).matches(&dir));
+        assert!(!ContainEntries::new(&["file.txt", "other.txt"]).matches(&dir));
+        assert!(!ContainEntries::new(&["file.txt"]).matches(&dir.join("file.txt")));
+    }
+
+    #[test]
+    fn test_contain_entries_failure_msg() {
+        let (_guard, dir) = scratch("entries_msg");
+        let msg = ContainEntries::new(&["file.txt", "other.txt"]).failure_message(&dir);
+        assert_eq!(msg, format!("expected directory {:?} to contain entries, missing [\"other.txt\"]", dir));
+    }
+
+    #[test]
+    fn test_contain_entries_negated_failure_msg() {
+        let (_guard, dir) = scratch("entries_negated_msg");
+        let msg = ContainEntries::new(&["file.txt"]).negated_failure_message(&dir);
+        assert_eq!(msg, format!("expected directory {:?} not to contain entries [\"file.txt\"]", dir));
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::Debug;
+use dsl::Matcher;
+
+pub struct PatternMatcher<F> {
+    pattern: &'static str,
+    predicate: F,
+}
+
+impl<F> PatternMatcher<F> {
+    pub fn new<A>(pattern: &'static str, predicate: F) -> PatternMatcher<F>
+    where F: Fn(&A) -> bool {
+        PatternMatcher {
+            pattern,
+            predicate,
+        }
+    }
+}
+
+impl<A: Debug, F: Fn(&A) -> bool> Matcher<A> for PatternMatcher<F> {
+    fn matches(&self, actual: &A) -> bool {
+        (self.predicate)(actual)
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} to match pattern `{}`", actual, self.pattern)
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to match pattern `{}`", actual, self.pattern)
+    }
+}
+
+/// Matcher that asserts a value matches the given pattern, with an optional guard.
+///
+/// Accepts any Rust pattern, including alternatives separated by `|`. The pattern text is
+/// included in the failure message.
+///
+/// ```
+/// #[macro_use] extern crate ferrous;
+/// use ferrous::dsl::*;
+///
+/// # fn main() {
+/// let actual: Option<u8> = Some(5);
+/// expect(&actual).to(match_pattern!(Some(n) if *n > 3));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! match_pattern {
+    ($($pattern:pat)|+ if $guard:expr) => {
+        $crate::dsl::__match_pattern(
+            concat!(stringify!($($pattern)|+), " if ", stringify!($guard)),
+            |actual| match actual {
+                $($pattern)|+ if $guard => true,
+                #[allow(unreachable_patterns)]
+                _ => false,
+            })
+    };
+    ($($pattern:pat)|+) => {
+        $crate::dsl::__match_pattern(
+            stringify!($($pattern)|+),
+            |actual| match actual {
+                $($pattern)|+ => true,
+                #[allow(unreachable_patterns)]
+                _ => false,
+            })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+
+    #[derive(Debug)]
+    enum State {
+        Connected { retries: u8 },
+        Disconnected,
+    }
+
+    #[test]
+    fn test_pattern_matches() {
+        let test = match_pattern!(State::Connected { retries: 0 });
+        assert!(test.matches(&State::Connected { retries: 0 }));
+        assert!(!test.matches(&State::Connected { retries: 1 }));
+        assert!(!test.matches(&State::Disconnected));
+    }
+
+    #[test]
+    fn test_pattern_alternatives_matches() {
+        let test = match_pattern!(State::Connected { .. } | State::Disconnected);
+        assert!(test.matches(&State::Disconnected));
+    }
+
+    #[test]
+    fn test_pattern_guard_matches() {
+        let test = match_pattern!(State::Connected { retries } if *retries < 3);
+        assert!(test.matches(&State::Connected { retries: 2 }));
+        assert!(!test.matches(&State::Connected { retries: 3 }));
+    }
+
+    #[test]
+    fn test_pattern_failure_msg() {
+        let test = match_pattern!(State::Connected { retries: 0, .. });
+        let msg = test.failure_message(&State::Disconnected);
+        assert_eq!(msg, String::from("expected Disconnected to match pattern \
+                                      `State::Connected { retries: 0, .. }`"));
+    }
+
+    #[test]
+    fn test_pattern_guard_negated_failure_msg() {
+        let test = match_pattern!(State::Connected { retries } if *retries < 3);
+        let msg = test.negated_failure_message(&State::Connected { retries: 1 });
+        assert_eq!(msg, String::from("expected Connected { retries: 1 } not to match pattern \
+                                      `State::Connected { retries } if *retries < 3`"));
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::Debug;
+use std::ops::{Bound, RangeBounds};
+use dsl::Matcher;
+
+// Interval notation for a range, e.g. `[1, 5)` or `(-∞, 10]`.
+fn describe_range<T: Debug, R: RangeBounds<T>>(range: &R) -> String {
+    let start = match range.start_bound() {
+        Bound::Included(start) => format!("[{:?}", start),
+        Bound::Excluded(start) => format!("({:?}", start),
+        Bound::Unbounded => String::from("(-∞"),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => format!("{:?}]", end),
+        Bound::Excluded(end) => format!("{:?})", end),
+        Bound::Unbounded => String::from("∞)"),
+    };
+    format!("{}, {}", start, end)
+}
+
+#[derive(Debug)]
+pub struct InRange<R> {
+    range: R,
+}
+
+impl<R> InRange<R> {
+    pub fn new(range: R) -> InRange<R> {
+        InRange {
+            range,
+        }
+    }
+
+    // Which bound the actual value falls outside of.
+    fn outside<T: Debug + PartialOrd>(&self, actual: &T) -> String where R: RangeBounds<T> {
+        let below = match self.range.start_bound() {
+            Bound::Included(start) if actual < start => Some(start),
+            Bound::Excluded(start) if actual <= start => Some(start),
+            _ => None,
+        };
+        let above = match self.range.end_bound() {
+            Bound::Included(end) if actual > end => Some(end),
+            Bound::Excluded(end) if actual >= end => Some(end),
+            _ => None,
+        };
+        match (below, above) {
+            (Some(start), _) => format!("below the lower bound {:?}", start),
+            (_, Some(end)) => format!("above the upper bound {:?}", end),
+            (None, None) => String::from("not comparable to the bounds"),
+        }
+    }
+}
+
+impl<T, R> Matcher<T> for InRange<R> where T: Debug + PartialOrd, R: RangeBounds<T> {
+    fn matches(&self, actual: &T) -> bool {
+        self.range.contains(actual)
+    }
+
+    fn failure_message(&self, actual: &T) -> String {
+        format!("expected {:?} to be in range {}, but it is {}",
+                actual, describe_range(&self.range), self.outside(actual))
+    }
+
+    fn negated_failure_message(&self, actual: &T) -> String {
+        format!("expected {:?} not to be in range {}", actual, describe_range(&self.range))
+    }
+}
+
+#[derive(Debug)]
+pub struct OneOf<'a, E: 'a> {
+    candidates: &'a [E],
+}
+
+impl<'a, E> OneOf<'a, E> {
+    pub fn new(candidates: &'a [E]) -> OneOf<'a, E> {
+        OneOf {
+            candidates,
+        }
+    }
+}
+
+impl<'a, A, E> Matcher<A> for OneOf<'a, E> where A: Debug + PartialEq<E>, E: Debug {
+    fn matches(&self, actual: &A) -> bool {
+        self.candidates.iter().any(|candidate| actual == candidate)
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} to be one of {:?}", actual, self.candidates)
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to be one of {:?}", actual, self.candidates)
+    }
+}
+
+pub struct OneOfMatching<M> {
+    matchers: Vec<M>,
+}
+
+impl<M> OneOfMatching<M> {
+    pub fn new(matchers: Vec<M>) -> OneOfMatching<M> {
+        OneOfMatching {
+            matchers,
+        }
+    }
+}
+
+impl<A: Debug, M: Matcher<A>> Matcher<A> for OneOfMatching<M> {
+    fn matches(&self, actual: &A) -> bool {
+        self.matchers.iter().any(|matcher| matcher.matches(actual))
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let failures: Vec<String> = self.matchers.iter()
+            .map(|matcher| format!("\n  {}", matcher.failure_message(actual)))
+            .collect();
+        format!("expected {:?} to match one of {} matchers, but none matched:{}",
+                actual, self.matchers.len(), failures.concat())
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let matched: Vec<String> = self.matchers.iter()
+            .filter(|matcher| matcher.matches(actual))
+            .map(|matcher| format!("\n  {}", matcher.negated_failure_message(actual)))
+            .collect();
+        format!("expected {:?} not to match any of {} matchers, but some matched:{}",
+                actual, self.matchers.len(), matched.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use dsl::matchers::EqualsValue;
+    use super::*;
+
+    #[test]
+    fn test_in_range_matches() {
+        assert!(InRange::new(1..5).matches(&1));
+        assert!(!InRange::new(1..5).matches(&5));
+        assert!(InRange::new(1..=5).matches(&5));
+        assert!(InRange::new(..0.5).matches(&0.1));
+        assert!(!InRange::new(0.0..).matches(&f64::NAN));
+        assert!(InRange::new("a".."c").matches(&"b"));
+    }
+
+    #[test]
+    fn test_in_range_failure_msg() {
+        let msg = InRange::new(1..5).failure_message(&7);
+        assert_eq!(msg, String::from("expected 7 to be in range [1, 5), but it is above the upper bound 5"));
+
+        let msg = InRange::new(1..=5).failure_message(&0);
+        assert_eq!(msg, String::from("expected 0 to be in range [1, 5], but it is below the lower bound 1"));
+
+        let msg = InRange::new(0.0..).failure_message(&f64::NAN);
+        assert_eq!(msg, String::from("expected NaN to be in range [0.0, ∞), but it is not comparable to the bounds"));
+    }
+
+    #[test]
+    fn test_in_range_negated_failure_msg() {
+        let msg = InRange::new(..=10).negated_failure_message(&3);
+        assert_eq!(msg, String::from("expected 3 not to be in range (-∞, 10]"));
+    }
+
+    #[test]
+    fn test_one_of_matches() {
+        let candidates = ["GET", "HEAD"];
+        let test = OneOf::new(&candidates);
+        assert!(test.matches(&"HEAD"));
+        assert!(test.matches(&String::from("GET")));
+        assert!(!test.matches(&"POST"));
+    }
+
+    #[test]
+    fn test_one_of_failure_msg() {
+        let candidates = [1, 2, 3];
+        let msg = OneOf::new(&candidates).failure_message(&4);
+        assert_eq!(msg, String::from("expected 4 to be one of [1, 2, 3]"));
+    }
+
+    #[test]
+    fn test_one_of_negated_failure_msg() {
+        let candidates = [1, 2, 3];
+        let msg = OneOf::new(&candidates).negated_failure_message(&2);
+        assert_eq!(msg, String::from("expected 2 not to be one of [1, 2, 3]"));
+    }
+
+    #[test]
+    fn test_one_of_matching_matches() {
+        let test: OneOfMatching<Box<dyn Matcher<i32>>> =
+            OneOfMatching::new(vec![Box::new(EqualsValue::new(0)), Box::new(InRange::new(10..20))]);
+        assert!(test.matches(&0));
+        assert!(test.matches(&15));
+        assert!(!test.matches(&5));
+    }
+
+    #[test]
+    fn test_one_of_matching_failure_msg() {
+        let test = OneOfMatching::new(vec![EqualsValue::new(1), EqualsValue::new(2)]);
+        let msg = test.failure_message(&3);
+        assert_eq!(msg, String::from("expected 3 to match one of 2 matchers, but none matched:\n  \
+                                      expected 1 to equal 3\n  \
+                                      expected 2 to equal 3"));
+    }
+
+    #[test]
+    fn test_one_of_matching_negated_failure_msg() {
+        let test = OneOfMatching::new(vec![EqualsValue::new(1), EqualsValue::new(2)]);
+        let msg = test.negated_failure_message(&2);
+        assert_eq!(msg, String::from("expected 2 not to match any of 2 matchers, but some matched:\n  \
+                                      expected 2 not to equal 2"));
+    }
+}
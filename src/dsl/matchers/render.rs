@@ -0,0 +1,138 @@
+use std::fmt::{Debug, Display};
+use dsl::Matcher;
+
+pub struct HaveDisplay<M> {
+    matcher: M,
+}
+
+impl<M> HaveDisplay<M> {
+    pub fn new(matcher: M) -> HaveDisplay<M> {
+        HaveDisplay {
+            matcher,
+        }
+    }
+}
+
+impl<A: Display, M: Matcher<String>> Matcher<A> for HaveDisplay<M> {
+    fn matches(&self, actual: &A) -> bool {
+        self.matcher.matches(&actual.to_string())
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let rendered = actual.to_string();
+        format!("expected Display output {:?} to match: {}", rendered, self.matcher.failure_message(&rendered))
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let rendered = actual.to_string();
+        format!("expected Display output {:?} not to match: {}",
+                rendered, self.matcher.negated_failure_message(&rendered))
+    }
+}
+
+pub struct HaveDebug<M> {
+    pretty: bool,
+    matcher: M,
+}
+
+impl<M> HaveDebug<M> {
+    pub fn new(pretty: bool, matcher: M) -> HaveDebug<M> {
+        HaveDebug {
+            pretty,
+            matcher,
+        }
+    }
+
+    fn render<A: Debug>(&self, actual: &A) -> String {
+        if self.pretty {
+            format!("{:#?}", actual)
+        } else {
+            format!("{:?}", actual)
+        }
+    }
+
+    fn output(&self) -> &'static str {
+        if self.pretty { "pretty Debug output" } else { "Debug output" }
+    }
+}
+
+impl<A: Debug, M: Matcher<String>> Matcher<A> for HaveDebug<M> {
+    fn matches(&self, actual: &A) -> bool {
+        self.matcher.matches(&self.render(actual))
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let rendered = self.render(actual);
+        format!("expected {} {:?} to match: {}", self.output(), rendered, self.matcher.failure_message(&rendered))
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let rendered = self.render(actual);
+        format!("expected {} {:?} not to match: {}",
+                self.output(), rendered, self.matcher.negated_failure_message(&rendered))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+    use dsl::Matcher;
+    use dsl::matchers::EqualsValue;
+    use super::*;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn test_have_display_matches() {
+        let test = HaveDisplay::new(EqualsValue::new("127.0.0.1"));
+        assert!(test.matches(&Ipv4Addr::LOCALHOST));
+        assert!(!test.matches(&Ipv4Addr::UNSPECIFIED));
+        assert!(HaveDisplay::new(EqualsValue::new("1.5")).matches(&1.5));
+    }
+
+    #[test]
+    fn test_have_display_failure_msg() {
+        let msg = HaveDisplay::new(EqualsValue::new("1.50")).failure_message(&1.5);
+        assert_eq!(msg, String::from("expected Display output \"1.5\" to match: expected \"1.50\" to equal \"1.5\""));
+    }
+
+    #[test]
+    fn test_have_display_negated_failure_msg() {
+        let msg = HaveDisplay::new(EqualsValue::new("x")).negated_failure_message(&'x');
+        assert_eq!(msg, String::from("expected Display output \"x\" not to match: expected \"x\" not to equal \"x\""));
+    }
+
+    #[test]
+    fn test_have_debug_matches() {
+        let test = HaveDebug::new(false, EqualsValue::new("Point { x: 1, y: 2 }"));
+        assert!(test.matches(&Point { x: 1, y: 2 }));
+        assert!(!test.matches(&Point { x: 2, y: 1 }));
+        assert!(HaveDebug::new(false, EqualsValue::new("\"quoted\"")).matches(&"quoted"));
+    }
+
+    #[test]
+    fn test_have_debug_failure_msg() {
+        let msg = HaveDebug::new(false, EqualsValue::new("Some(2)")).failure_message(&Some(1));
+        assert_eq!(msg, String::from("expected Debug output \"Some(1)\" to match: \
+                                      expected \"Some(2)\" to equal \"Some(1)\""));
+    }
+
+    #[test]
+    fn test_have_pretty_debug_matches() {
+        let test = HaveDebug::new(true, EqualsValue::new("Point {\n    x: 1,\n    y: 2,\n}"));
+        assert!(test.matches(&Point { x: 1, y: 2 }));
+        assert!(!test.matches(&Point { x: 1, y: 3 }));
+    }
+
+    #[test]
+    fn test_have_pretty_debug_negated_failure_msg() {
+        let msg = HaveDebug::new(true, EqualsValue::new("[\n    1,\n]")).negated_failure_message(&[1]);
+        assert_eq!(msg, String::from("expected pretty Debug output \"[\\n    1,\\n]\" not to match: \
+                                      expected \"[\\n    1,\\n]\" not to equal \"[\\n    1,\\n]\""));
+    }
+}
@@ -37,9 +37,92 @@ impl<E: Debug, O: Debug> Matcher<Result<O, E>> for ResultMatcher {
     }
 }
 
+pub struct OkWith<M> {
+    matcher: M,
+}
+
+impl<M> OkWith<M> {
+    pub fn new(matcher: M) -> OkWith<M> {
+        OkWith {
+            matcher,
+        }
+    }
+}
+
+impl<E: Debug, O: Debug, M: Matcher<O>> Matcher<Result<O, E>> for OkWith<M> {
+    fn matches(&self, actual: &Result<O, E>) -> bool {
+        match *actual {
+            Ok(ref value) => self.matcher.matches(value),
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &Result<O, E>) -> String {
+        match *actual {
+            Ok(ref value) => {
+                format!("expected Ok variant whose value matches: {}, found {:?}",
+                        self.matcher.failure_message(value), actual)
+            },
+            Err(_) => format!("expected Ok variant, found {:?}", actual),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &Result<O, E>) -> String {
+        match *actual {
+            Ok(ref value) => {
+                format!("expected Ok variant whose value does not match: {}, found {:?}",
+                        self.matcher.negated_failure_message(value), actual)
+            },
+            Err(_) => format!("expected Ok variant, found {:?}", actual),
+        }
+    }
+}
+
+pub struct ErrWith<M> {
+    matcher: M,
+}
+
+impl<M> ErrWith<M> {
+    pub fn new(matcher: M) -> ErrWith<M> {
+        ErrWith {
+            matcher,
+        }
+    }
+}
+
+impl<E: Debug, O: Debug, M: Matcher<E>> Matcher<Result<O, E>> for ErrWith<M> {
+    fn matches(&self, actual: &Result<O, E>) -> bool {
+        match *actual {
+            Err(ref value) => self.matcher.matches(value),
+            Ok(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &Result<O, E>) -> String {
+        match *actual {
+            Err(ref value) => {
+                format!("expected Err variant whose value matches: {}, found {:?}",
+                        self.matcher.failure_message(value), actual)
+            },
+            Ok(_) => format!("expected Err variant, found {:?}", actual),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &Result<O, E>) -> String {
+        match *actual {
+            Err(ref value) => {
+                format!("expected Err variant whose value does not match: {}, found {:?}",
+                        self.matcher.negated_failure_message(value), actual)
+            },
+            Ok(_) => format!("expected Err variant, found {:?}", actual),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use dsl::Matcher;
+    use dsl::matchers::Equals;
     use super::*;
 
     #[test]
@@ -93,4 +176,47 @@ mod tests {
         let msg = test.negated_failure_message(&Ok(1u8) as &Result<u8, u8>);
         assert_eq!(msg, String::from("expected Err variant, found Ok(1)"));
     }
+
+    #[test]
+    fn test_ok_with_matches() {
+        let expected = 5u8;
+        let test = OkWith::new(Equals::new(&expected));
+        assert!(test.matches(&Ok(5u8) as &Result<u8, u8>));
+        assert!(!test.matches(&Ok(1u8) as &Result<u8, u8>));
+        assert!(!test.matches(&Err(5u8) as &Result<u8, u8>));
+    }
+
+    #[test]
+    fn test_ok_with_failure_msg() {
+        let expected = 5u8;
+        let test = OkWith::new(Equals::new(&expected));
+        let msg = test.failure_message(&Ok(1u8) as &Result<u8, u8>);
+        assert_eq!(msg, String::from("expected Ok variant whose value matches: \
+                                      expected 5 to equal 1, found Ok(1)"));
+    }
+
+    #[test]
+    fn test_ok_with_err_failure_msg() {
+        let expected = 5u8;
+        let test = OkWith::new(Equals::new(&expected));
+        let msg = test.failure_message(&Err(1u8) as &Result<u8, u8>);
+        assert_eq!(msg, String::from("expected Ok variant, found Err(1)"));
+    }
+
+    #[test]
+    fn test_err_with_matches() {
+        let expected = 5u8;
+        let test = ErrWith::new(Equals::new(&expected));
+        assert!(test.matches(&Err(5u8) as &Result<u8, u8>));
+        assert!(!test.matches(&Ok(5u8) as &Result<u8, u8>));
+    }
+
+    #[test]
+    fn test_err_with_negated_failure_msg() {
+        let expected = 5u8;
+        let test = ErrWith::new(Equals::new(&expected));
+        let msg = test.negated_failure_message(&Err(5u8) as &Result<u8, u8>);
+        assert_eq!(msg, String::from("expected Err variant whose value does not match: \
+                                      expected 5 not to equal 5, found Err(5)"));
+    }
 }
@@ -0,0 +1,211 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+use dsl::Matcher;
+
+#[derive(Debug)]
+pub struct RoundTripViaString;
+
+impl<A> Matcher<A> for RoundTripViaString
+where A: Debug + Display + FromStr + PartialEq, A::Err: Debug {
+    fn matches(&self, actual: &A) -> bool {
+        match actual.to_string().parse::<A>() {
+            Ok(parsed) => parsed == *actual,
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let rendered = actual.to_string();
+        match rendered.parse::<A>() {
+            Ok(parsed) => {
+                format!("expected {:?} to round-trip via its string form {:?}, but it parsed back as {:?}",
+                        actual, rendered, parsed)
+            },
+            Err(err) => {
+                format!("expected {:?} to round-trip via its string form {:?}, but parsing failed with {:?}",
+                        actual, rendered, err)
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to round-trip via its string form {:?}, but it parsed back as an equal value",
+                actual, actual.to_string())
+    }
+}
+
+pub struct RoundTrip<E, D> {
+    encode: E,
+    decode: D,
+}
+
+impl<E, D> RoundTrip<E, D> {
+    pub fn new<A, T, X>(encode: E, decode: D) -> RoundTrip<E, D>
+    where E: Fn(&A) -> T, D: Fn(&T) -> Result<A, X> {
+        RoundTrip {
+            encode,
+            decode,
+        }
+    }
+}
+
+impl<A, T, X, E, D> Matcher<A> for RoundTrip<E, D>
+where A: Debug + PartialEq, T: Debug, X: Debug, E: Fn(&A) -> T, D: Fn(&T) -> Result<A, X> {
+    fn matches(&self, actual: &A) -> bool {
+        match (self.decode)(&(self.encode)(actual)) {
+            Ok(decoded) => decoded == *actual,
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let encoded = (self.encode)(actual);
+        match (self.decode)(&encoded) {
+            Ok(decoded) => {
+                format!("expected {:?} to round-trip via {:?}, but it decoded as {:?}", actual, encoded, decoded)
+            },
+            Err(err) => {
+                format!("expected {:?} to round-trip via {:?}, but decoding failed with {:?}", actual, encoded, err)
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to round-trip via {:?}, but it decoded as an equal value",
+                actual, (self.encode)(actual))
+    }
+}
+
+#[derive(Debug)]
+pub struct CloneEqual;
+
+impl<A: Clone + Debug + PartialEq> Matcher<A> for CloneEqual {
+    fn matches(&self, actual: &A) -> bool {
+        actual.clone() == *actual
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} to equal its clone, but the clone was {:?}", actual, actual.clone())
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!("expected {:?} not to equal its clone {:?}", actual, actual.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::num::ParseIntError;
+    use std::str::FromStr;
+    use dsl::Matcher;
+    use super::*;
+
+    // Loses its sign when rendered, so negative values do not round-trip.
+    #[derive(Debug, PartialEq)]
+    struct Magnitude(i32);
+
+    impl fmt::Display for Magnitude {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", self.0.abs())
+        }
+    }
+
+    impl FromStr for Magnitude {
+        type Err = ParseIntError;
+
+        fn from_str(s: &str) -> Result<Magnitude, ParseIntError> {
+            s.parse().map(Magnitude)
+        }
+    }
+
+    // Forgets its generation when cloned.
+    #[derive(Debug, PartialEq)]
+    struct Versioned {
+        value: u8,
+        generation: u32,
+    }
+
+    impl Clone for Versioned {
+        fn clone(&self) -> Versioned {
+            Versioned { value: self.value, generation: 0 }
+        }
+    }
+
+    #[test]
+    fn test_round_trip_via_string_matches() {
+        assert!(RoundTripViaString.matches(&Magnitude(5)));
+        assert!(!RoundTripViaString.matches(&Magnitude(-5)));
+        assert!(RoundTripViaString.matches(&1.5));
+        assert!(!RoundTripViaString.matches(&f64::NAN));
+    }
+
+    #[test]
+    fn test_round_trip_via_string_failure_msg() {
+        let msg = RoundTripViaString.failure_message(&Magnitude(-5));
+        assert_eq!(msg, String::from("expected Magnitude(-5) to round-trip via its string form \"5\", \
+                                      but it parsed back as Magnitude(5)"));
+
+        let msg = RoundTripViaString.failure_message(&f64::NAN);
+        assert_eq!(msg, String::from("expected NaN to round-trip via its string form \"NaN\", but it parsed back as NaN"));
+    }
+
+    #[test]
+    fn test_round_trip_via_string_negated_failure_msg() {
+        let msg = RoundTripViaString.negated_failure_message(&Magnitude(5));
+        assert_eq!(msg, String::from("expected Magnitude(5) not to round-trip via its string form \"5\", \
+                                      but it parsed back as an equal value"));
+    }
+
+    #[test]
+    fn test_round_trip_matches() {
+        let test = RoundTrip::new(|x: &u16| x.to_be_bytes().to_vec(),
+                                  |bytes: &Vec<u8>| -> Result<u16, String> {
+                                      Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+                                  });
+        assert!(test.matches(&0xbeef));
+
+        let lossy = RoundTrip::new(|x: &u16| (*x as u8).to_string(), |s: &String| s.parse::<u16>());
+        assert!(lossy.matches(&200));
+        assert!(!lossy.matches(&300));
+    }
+
+    #[test]
+    fn test_round_trip_failure_msg() {
+        let lossy = RoundTrip::new(|x: &u16| (*x as u8).to_string(), |s: &String| s.parse::<u16>());
+        let msg = lossy.failure_message(&300);
+        assert_eq!(msg, String::from("expected 300 to round-trip via \"44\", but it decoded as 44"));
+
+        let broken = RoundTrip::new(|x: &i32| format!("#{}", x), |s: &String| s.parse::<i32>());
+        let msg = broken.failure_message(&7);
+        assert_eq!(msg, String::from("expected 7 to round-trip via \"#7\", \
+                                      but decoding failed with ParseIntError { kind: InvalidDigit }"));
+    }
+
+    #[test]
+    fn test_round_trip_negated_failure_msg() {
+        let test = RoundTrip::new(|x: &i32| x.to_string(), |s: &String| s.parse::<i32>());
+        let msg = test.negated_failure_message(&-7);
+        assert_eq!(msg, String::from("expected -7 not to round-trip via \"-7\", but it decoded as an equal value"));
+    }
+
+    #[test]
+    fn test_clone_equal_matches() {
+        assert!(CloneEqual.matches(&vec![1, 2, 3]));
+        assert!(CloneEqual.matches(&Versioned { value: 1, generation: 0 }));
+        assert!(!CloneEqual.matches(&Versioned { value: 1, generation: 3 }));
+    }
+
+    #[test]
+    fn test_clone_equal_failure_msg() {
+        let msg = CloneEqual.failure_message(&Versioned { value: 1, generation: 3 });
+        assert_eq!(msg, String::from("expected Versioned { value: 1, generation: 3 } to equal its clone, \
+                                      but the clone was Versioned { value: 1, generation: 0 }"));
+    }
+
+    #[test]
+    fn test_clone_equal_negated_failure_msg() {
+        let msg = CloneEqual.negated_failure_message(&Some(1));
+        assert_eq!(msg, String::from("expected Some(1) not to equal its clone Some(1)"));
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+use dsl::Matcher;
+
+const MAX_LISTED: usize = 16;
+
+/// Common view over set types and sequences used as sets, with membership tests and a stable
+/// element order for failure messages.
+///
+/// Ordered sets list their natural order and sequences their own order, while hashed sets are
+/// sorted by the Debug rendering of their elements so that failure messages are deterministic.
+pub trait SetElements {
+    /// The element type of the set.
+    type Element: Debug;
+
+    /// Whether any element satisfies the predicate, stopping at the first that does.
+    fn any_element<F: FnMut(&Self::Element) -> bool>(&self, predicate: F) -> bool;
+
+    /// Calls `f` with a membership test for this set, using the set's own lookup, or a hashed
+    /// index built once for sequences.
+    fn with_lookup<R, F: FnOnce(&dyn Fn(&Self::Element) -> bool) -> R>(&self, f: F) -> R;
+
+    /// All elements, in a stable order. Only needed for failure messages.
+    fn set_elements(&self) -> Vec<&Self::Element>;
+}
+
+impl<T: Debug + Eq + Hash, S: BuildHasher> SetElements for HashSet<T, S> {
+    type Element = T;
+
+    fn any_element<F: FnMut(&T) -> bool>(&self, predicate: F) -> bool {
+        self.iter().any(predicate)
+    }
+
+    fn with_lookup<R, F: FnOnce(&dyn Fn(&T) -> bool) -> R>(&self, f: F) -> R {
+        f(&|element| self.contains(element))
+    }
+
+    fn set_elements(&self) -> Vec<&T> {
+        let mut elements: Vec<_> = self.iter().collect();
+        elements.sort_by_cached_key(|e| format!("{:?}", e));
+        elements
+    }
+}
+
+impl<T: Debug + Ord> SetElements for BTreeSet<T> {
+    type Element = T;
+
+    fn any_element<F: FnMut(&T) -> bool>(&self, predicate: F) -> bool {
+        self.iter().any(predicate)
+    }
+
+    fn with_lookup<R, F: FnOnce(&dyn Fn(&T) -> bool) -> R>(&self, f: F) -> R {
+        f(&|element| self.contains(element))
+    }
+
+    fn set_elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T: Debug + Eq + Hash> SetElements for [T] {
+    type Element = T;
+
+    fn any_element<F: FnMut(&T) -> bool>(&self, predicate: F) -> bool {
+        self.iter().any(predicate)
+    }
+
+    fn with_lookup<R, F: FnOnce(&dyn Fn(&T) -> bool) -> R>(&self, f: F) -> R {
+        let index: HashSet<&T> = self.iter().collect();
+        f(&|element| index.contains(element))
+    }
+
+    // Repeated elements are listed once, at their first position.
+    fn set_elements(&self) -> Vec<&T> {
+        let mut seen = HashSet::new();
+        self.iter().filter(|&e| seen.insert(e)).collect()
+    }
+}
+
+// Sequences that dereference to a slice are treated as the set of their elements.
+impl<T> SetElements for Vec<T> where T: Debug + Eq + Hash {
+    type Element = T;
+
+    fn any_element<F: FnMut(&T) -> bool>(&self, predicate: F) -> bool {
+        self[..].any_element(predicate)
+    }
+
+    fn with_lookup<R, F: FnOnce(&dyn Fn(&T) -> bool) -> R>(&self, f: F) -> R {
+        self[..].with_lookup(f)
+    }
+
+    fn set_elements(&self) -> Vec<&T> {
+        self[..].set_elements()
+    }
+}
+
+impl<T> SetElements for &[T] where T: Debug + Eq + Hash {
+    type Element = T;
+
+    fn any_element<F: FnMut(&T) -> bool>(&self, predicate: F) -> bool {
+        self[..].any_element(predicate)
+    }
+
+    fn with_lookup<R, F: FnOnce(&dyn Fn(&T) -> bool) -> R>(&self, f: F) -> R {
+        self[..].with_lookup(f)
+    }
+
+    fn set_elements(&self) -> Vec<&T> {
+        self[..].set_elements()
+    }
+}
+
+impl<T, const N: usize> SetElements for [T; N] where T: Debug + Eq + Hash {
+    type Element = T;
+
+    fn any_element<F: FnMut(&T) -> bool>(&self, predicate: F) -> bool {
+        self[..].any_element(predicate)
+    }
+
+    fn with_lookup<R, F: FnOnce(&dyn Fn(&T) -> bool) -> R>(&self, f: F) -> R {
+        self[..].with_lookup(f)
+    }
+
+    fn set_elements(&self) -> Vec<&T> {
+        self[..].set_elements()
+    }
+}
+
+#[derive(Debug)]
+pub enum SetMatcher<'a, E: 'a + ?Sized> {
+    SubsetOf(&'a E),
+    SupersetOf(&'a E),
+    DisjointFrom(&'a E),
+    IntersectWith(&'a E),
+}
+
+// Whether some element of `a` is not contained in `b`.
+fn has_difference<A, B>(a: &A, b: &B) -> bool
+where A: SetElements + ?Sized, B: SetElements<Element=A::Element> + ?Sized {
+    b.with_lookup(|contains| a.any_element(|e| !contains(e)))
+}
+
+// Whether some element of `a` is also contained in `b`.
+fn has_intersection<A, B>(a: &A, b: &B) -> bool
+where A: SetElements + ?Sized, B: SetElements<Element=A::Element> + ?Sized {
+    b.with_lookup(|contains| a.any_element(|e| contains(e)))
+}
+
+// The elements of `a` whose membership in `b` is as given, listed for a failure message.
+fn listed<A, B>(a: &A, b: &B, in_b: bool) -> String
+where A: SetElements + ?Sized, B: SetElements<Element=A::Element> + ?Sized {
+    let elements: Vec<_> = b.with_lookup(|contains| {
+        a.set_elements().into_iter().filter(|e| contains(e) == in_b).collect()
+    });
+    if elements.len() > MAX_LISTED {
+        format!("{:?} and {} more", &elements[..MAX_LISTED], elements.len() - MAX_LISTED)
+    } else {
+        format!("{:?}", elements)
+    }
+}
+
+impl<'e, E, I> Matcher<I> for SetMatcher<'e, E>
+where I: SetElements, E: SetElements<Element=I::Element> + ?Sized {
+    fn matches(&self, actual: &I) -> bool {
+        match *self {
+            SetMatcher::SubsetOf(expected) => !has_difference(actual, expected),
+            SetMatcher::SupersetOf(expected) => !has_difference(expected, actual),
+            SetMatcher::DisjointFrom(expected) => !has_intersection(actual, expected),
+            SetMatcher::IntersectWith(expected) => has_intersection(actual, expected),
+        }
+    }
+
+    fn failure_message(&self, actual: &I) -> String {
+        match *self {
+            SetMatcher::SubsetOf(expected) => {
+                format!("expected to be a subset, found elements not in the other set: {}",
+                        listed(actual, expected, false))
+            },
+            SetMatcher::SupersetOf(expected) => {
+                format!("expected to be a superset, missing elements: {}",
+                        listed(expected, actual, false))
+            },
+            SetMatcher::DisjointFrom(expected) => {
+                format!("expected to be disjoint, found common elements: {}",
+                        listed(actual, expected, true))
+            },
+            SetMatcher::IntersectWith(_) => {
+                String::from("expected to intersect, found no common elements")
+            },
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &I) -> String {
+        match *self {
+            SetMatcher::SubsetOf(_) => {
+                String::from("expected not to be a subset, found every element in the other set")
+            },
+            SetMatcher::SupersetOf(_) => {
+                String::from("expected not to be a superset, found every element of the other set")
+            },
+            SetMatcher::DisjointFrom(_) => {
+                String::from("expected not to be disjoint, found no common elements")
+            },
+            SetMatcher::IntersectWith(expected) => {
+                format!("expected not to intersect, found common elements: {}",
+                        listed(actual, expected, true))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use super::*;
+
+    fn btree(elements: &[u8]) -> BTreeSet<u8> {
+        elements.iter().cloned().collect()
+    }
+
+    fn hash(elements: &[u8]) -> HashSet<u8> {
+        elements.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_subset_matches() {
+        let expected = btree(&[1, 2, 3]);
+        assert!(SetMatcher::SubsetOf(&expected).matches(&btree(&[1, 3])));
+        assert!(!SetMatcher::SubsetOf(&expected).matches(&btree(&[1, 4])));
+    }
+
+    #[test]
+    fn test_subset_failure_msg() {
+        let expected = btree(&[1, 2, 3]);
+        let msg = SetMatcher::SubsetOf(&expected).failure_message(&btree(&[1, 4, 5]));
+        assert_eq!(msg, String::from("expected to be a subset, found elements not in the other set: [4, 5]"));
+    }
+
+    #[test]
+    fn test_subset_negated_failure_msg() {
+        let expected = hash(&[1, 2, 3]);
+        let msg = SetMatcher::SubsetOf(&expected).negated_failure_message(&hash(&[1]));
+        assert_eq!(msg, String::from("expected not to be a subset, found every element in the other set"));
+    }
+
+    #[test]
+    fn test_superset_matches() {
+        let expected = hash(&[1, 2]);
+        assert!(SetMatcher::SupersetOf(&expected).matches(&hash(&[1, 2, 3])));
+        assert!(!SetMatcher::SupersetOf(&expected).matches(&hash(&[1, 3])));
+    }
+
+    #[test]
+    fn test_superset_failure_msg() {
+        let expected = hash(&[3, 1, 2, 20, 10]);
+        let msg = SetMatcher::SupersetOf(&expected).failure_message(&btree(&[1]));
+        assert_eq!(msg, String::from("expected to be a superset, missing elements: [10, 2, 20, 3]"));
+    }
+
+    #[test]
+    fn test_disjoint_matches() {
+        let expected = btree(&[1, 2]);
+        assert!(SetMatcher::DisjointFrom(&expected).matches(&btree(&[3, 4])));
+        assert!(!SetMatcher::DisjointFrom(&expected).matches(&btree(&[2, 3])));
+    }
+
+    #[test]
+    fn test_disjoint_failure_msg() {
+        let expected = btree(&[1, 2, 3]);
+        let msg = SetMatcher::DisjointFrom(&expected).failure_message(&btree(&[2, 3, 4]));
+        assert_eq!(msg, String::from("expected to be disjoint, found common elements: [2, 3]"));
+    }
+
+    #[test]
+    fn test_intersect_matches() {
+        let expected = hash(&[1, 2]);
+        assert!(SetMatcher::IntersectWith(&expected).matches(&hash(&[2, 3])));
+        assert!(!SetMatcher::IntersectWith(&expected).matches(&hash(&[3, 4])));
+    }
+
+    #[test]
+    fn test_intersect_failure_msg() {
+        let expected = hash(&[1, 2]);
+        let msg = SetMatcher::IntersectWith(&expected).failure_message(&hash(&[3]));
+        assert_eq!(msg, String::from("expected to intersect, found no common elements"));
+    }
+
+    #[test]
+    fn test_sequence_matches() {
+        let expected = vec![1, 2, 3, 2];
+        assert!(SetMatcher::SubsetOf(&expected).matches(&vec![3, 1, 1]));
+        assert!(!SetMatcher::SubsetOf(&expected).matches(&vec![3, 4]));
+        assert!(SetMatcher::SupersetOf(&expected[..]).matches(&hash(&[1, 2, 3, 4])));
+        assert!(SetMatcher::DisjointFrom(&[7, 8]).matches(&btree(&[1, 2])));
+        assert!(SetMatcher::IntersectWith(&expected).matches(&&[5, 3][..]));
+        assert!(!SetMatcher::IntersectWith(&hash(&[5])).matches(&vec![1, 2]));
+    }
+
+    #[test]
+    fn test_sequence_failure_msg() {
+        let expected = [1, 2];
+        let msg = SetMatcher::SubsetOf(&expected[..]).failure_message(&vec![4, 1, 3, 4]);
+        assert_eq!(msg, String::from("expected to be a subset, found elements not in the other set: [4, 3]"));
+    }
+
+    #[test]
+    fn test_many_elements_failure_msg() {
+        let expected: Vec<u32> = (0..100).collect();
+        let msg = SetMatcher::DisjointFrom(&expected).failure_message(&expected);
+        assert_eq!(msg, String::from("expected to be disjoint, found common elements: \
+                                      [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15] and 84 more"));
+    }
+
+    #[test]
+    fn test_intersect_negated_failure_msg() {
+        let expected = btree(&[1, 2, 3]);
+        let msg = SetMatcher::IntersectWith(&expected).negated_failure_message(&btree(&[3, 4]));
+        assert_eq!(msg, String::from("expected not to intersect, found common elements: [3]"));
+    }
+}
@@ -0,0 +1,317 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::{Debug, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use diff::unified_diff;
+use dsl::Matcher;
+
+// Environment variable that, when set to `1`, makes snapshot matchers rewrite the snapshots that
+// fail to match.
+const UPDATE_SNAPSHOTS_VAR: &str = "FERROUS_UPDATE_SNAPSHOTS";
+
+thread_local! {
+    // How many snapshots have been taken under each name on this thread. Every test runs on its
+    // own thread, so this lets a test take several snapshots under its default name.
+    static TAKEN: RefCell<HashMap<String, usize>> = RefCell::new(HashMap::new());
+}
+
+// The name of the next snapshot on this thread: the given name, or the module path and name of the
+// running test function joined by `__`, suffixed with a counter when it has already been used.
+fn snapshot_name(name: Option<&str>) -> String {
+    let name = match name {
+        Some(name) => name.to_owned(),
+        None => {
+            let current = thread::current();
+            current.name().unwrap_or("snapshot").replace("::", "__")
+        },
+    };
+
+    let count = TAKEN.with(|taken| {
+        let mut taken = taken.borrow_mut();
+        let count = taken.entry(name.clone()).or_insert(0);
+        *count += 1;
+        *count
+    });
+    if count == 1 {
+        name
+    } else {
+        format!("{}-{}", name, count)
+    }
+}
+
+// `file!()` is relative to the directory rustc was invoked from, which is the workspace root
+// rather than the package root for workspace members, so look for it in each ancestor.
+fn source_dir(manifest_dir: &str, source_file: &str) -> PathBuf {
+    let manifest_dir = Path::new(manifest_dir);
+    let file = manifest_dir.ancestors()
+        .map(|dir| dir.join(source_file))
+        .find(|file| file.exists())
+        .unwrap_or_else(|| manifest_dir.join(source_file));
+    file.parent().map(Path::to_path_buf).unwrap_or(file)
+}
+
+#[derive(Debug)]
+pub struct Snapshot {
+    name: String,
+    path: PathBuf,
+    update: bool,
+}
+
+impl Snapshot {
+    pub fn new(name: String, path: PathBuf, update: bool) -> Snapshot {
+        Snapshot {
+            name,
+            path,
+            update,
+        }
+    }
+
+    /// Resolves the snapshot file `snapshots/<name>.snap` next to the given source file.
+    pub fn beside(manifest_dir: &str, source_file: &str, name: Option<&str>) -> Snapshot {
+        let name = snapshot_name(name);
+        let path = source_dir(manifest_dir, source_file)
+            .join("snapshots")
+            .join(format!("{}.snap", name));
+        let update = env::var(UPDATE_SNAPSHOTS_VAR).map(|v| v == "1").unwrap_or(false);
+        Snapshot::new(name, path, update)
+    }
+
+    /// Stores the Display rendering of the actual value instead of the Debug rendering.
+    pub fn using_display(self) -> DisplaySnapshot {
+        DisplaySnapshot {
+            snapshot: self,
+        }
+    }
+
+    fn read(&self) -> io::Result<String> {
+        let mut stored = fs::read_to_string(&self.path)?;
+        if stored.ends_with('\n') {
+            stored.pop();
+        }
+        Ok(stored)
+    }
+
+    fn write(&self, rendered: &str) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, format!("{}\n", rendered))
+    }
+
+    fn matches_rendered(&self, rendered: &str) -> bool {
+        self.read().map(|stored| stored == rendered).unwrap_or(false)
+    }
+
+    // Snapshots are only ever written here, once a positive expectation has failed, so that a
+    // negated expectation can never pass by creating the snapshot it compares against.
+    fn failure_message_rendered(&self, rendered: &str) -> String {
+        let stored = self.read();
+        if self.update {
+            let stored = stored.unwrap_or_default();
+            return match self.write(rendered) {
+                Ok(()) => format!("snapshot {:?} did not match and was updated at {:?}; rerun to check it:\n{}",
+                                  self.name, self.path, unified_diff(&stored, rendered, "snapshot", "actual")),
+                Err(err) => format!("expected snapshot {:?} to be updated at {:?}, but it could not be written: {}",
+                                    self.name, self.path, err),
+            }
+        }
+
+        match stored {
+            Ok(stored) => {
+                format!("expected snapshot {:?} to match {:?} (set {}=1 to update it):\n{}",
+                        self.name, self.path, UPDATE_SNAPSHOTS_VAR,
+                        unified_diff(&stored, rendered, "snapshot", "actual"))
+            },
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => {
+                format!("expected snapshot {:?} to be stored at {:?}, but it does not exist (set {}=1 to create it)",
+                        self.name, self.path, UPDATE_SNAPSHOTS_VAR)
+            },
+            Err(err) => format!("expected snapshot {:?} to be stored at {:?}, but it could not be accessed: {}",
+                                self.name, self.path, err),
+        }
+    }
+
+    fn negated_failure_message_rendered(&self) -> String {
+        format!("expected snapshot {:?} not to match {:?}", self.name, self.path)
+    }
+}
+
+impl<A: Debug> Matcher<A> for Snapshot {
+    fn matches(&self, actual: &A) -> bool {
+        self.matches_rendered(&format!("{:#?}", actual))
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        self.failure_message_rendered(&format!("{:#?}", actual))
+    }
+
+    fn negated_failure_message(&self, _actual: &A) -> String {
+        self.negated_failure_message_rendered()
+    }
+}
+
+#[derive(Debug)]
+pub struct DisplaySnapshot {
+    snapshot: Snapshot,
+}
+
+impl<A: Display> Matcher<A> for DisplaySnapshot {
+    fn matches(&self, actual: &A) -> bool {
+        self.snapshot.matches_rendered(&actual.to_string())
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        self.snapshot.failure_message_rendered(&actual.to_string())
+    }
+
+    fn negated_failure_message(&self, _actual: &A) -> String {
+        self.snapshot.negated_failure_message_rendered()
+    }
+}
+
+/// Matcher that compares the pretty Debug rendering of the actual value with a snapshot stored
+/// in `snapshots/<name>.snap` next to the calling source file.
+///
+/// The name defaults to the module path and name of the running test function, e.g.
+/// `tests__my_test`. Each further snapshot taken under the same name in one test gets a numbered
+/// suffix, e.g. `tests__my_test-2`. Missing snapshots fail, and existing ones fail with a diff when
+/// the rendering changes. With `FERROUS_UPDATE_SNAPSHOTS=1` set, a failing snapshot is also
+/// rewritten, so the next run passes. Call `using_display()` to store the Display rendering
+/// instead.
+///
+/// ```no_run
+/// #[macro_use] extern crate ferrous;
+/// use ferrous::dsl::*;
+///
+/// # fn main() {
+/// let report = vec![("alice", 3), ("bob", 5)];
+/// expect(&report).to(match_snapshot!());
+/// expect(&"rendered\noutput").to(match_snapshot!("output").using_display());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! match_snapshot {
+    () => {
+        $crate::dsl::__match_snapshot(env!("CARGO_MANIFEST_DIR"), file!(), None)
+    };
+    ($name:expr) => {
+        $crate::dsl::__match_snapshot(env!("CARGO_MANIFEST_DIR"), file!(), Some($name))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use dsl::Matcher;
+    use super::*;
+
+    // Removes its scratch directory when the test ends, whether or not it passed.
+    struct ScratchDir(PathBuf);
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // A fresh scratch directory and the path of a snapshot with the given name inside it.
+    fn scratch(name: &str) -> (ScratchDir, PathBuf) {
+        let dir = env::temp_dir().join(format!("ferrous-snapshot-{}-{}", ::std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("snapshots").join(format!("{}.snap", name));
+        (ScratchDir(dir), path)
+    }
+
+    #[test]
+    fn test_snapshot_name_defaults_to_test_name() {
+        let name = "dsl__matchers__snapshot__tests__test_snapshot_name_defaults_to_test_name";
+        assert_eq!(snapshot_name(None), name);
+        assert_eq!(snapshot_name(None), format!("{}-2", name));
+        assert_eq!(snapshot_name(Some("table")), "table");
+        assert_eq!(snapshot_name(Some("table")), "table-2");
+    }
+
+    #[test]
+    fn test_snapshot_beside_source_file() {
+        let snapshot = Snapshot::beside(env!("CARGO_MANIFEST_DIR"), file!(), Some("beside"));
+        let expected = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/dsl/matchers/snapshots/beside.snap");
+        assert_eq!(snapshot.path, expected);
+    }
+
+    #[test]
+    fn test_snapshot_matches() {
+        let (_dir, path) = scratch("matches");
+        let test = Snapshot::new(String::from("matches"), path.clone(), false);
+        assert!(!test.matches(&vec![1, 2]));
+        assert!(!path.exists());
+
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "[\n    1,\n    2,\n]\n").unwrap();
+        assert!(test.matches(&vec![1, 2]));
+        assert!(!test.matches(&vec![1, 3]));
+    }
+
+    #[test]
+    fn test_snapshot_update() {
+        let (_dir, path) = scratch("update");
+        let test = Snapshot::new(String::from("update"), path.clone(), true);
+        assert!(!test.matches(&1));
+        let msg = test.failure_message(&1);
+        assert_eq!(msg, format!("snapshot \"update\" did not match and was updated at {:?}; rerun to check it:\n\
+                                 --- snapshot\n+++ actual\n@@ -0,0 +1,1 @@\n+1", path));
+        assert!(test.matches(&1));
+
+        assert!(!test.matches(&2));
+        test.failure_message(&2);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2\n");
+    }
+
+    #[test]
+    fn test_snapshot_missing_failure_msg() {
+        let (_dir, path) = scratch("missing");
+        let test = Snapshot::new(String::from("missing"), path.clone(), false);
+        let msg = test.failure_message(&1);
+        assert_eq!(msg, format!("expected snapshot \"missing\" to be stored at {:?}, but it does not exist \
+                                 (set FERROUS_UPDATE_SNAPSHOTS=1 to create it)", path));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_snapshot_failure_msg() {
+        let (_dir, path) = scratch("failure");
+        let test = Snapshot::new(String::from("failure"), path.clone(), false);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "[\n    1,\n    2,\n]\n").unwrap();
+        let msg = test.failure_message(&vec![1, 3]);
+        assert_eq!(msg, format!("expected snapshot \"failure\" to match {:?} \
+                                 (set FERROUS_UPDATE_SNAPSHOTS=1 to update it):\n\
+                                 --- snapshot\n+++ actual\n@@ -1,4 +1,4 @@\n [\n     1,\n-    2,\n+    3,\n ]",
+                                path));
+    }
+
+    #[test]
+    fn test_snapshot_negated_failure_msg() {
+        let (_dir, path) = scratch("negated");
+        let test = Snapshot::new(String::from("negated"), path.clone(), false);
+        let msg = test.negated_failure_message(&1);
+        assert_eq!(msg, format!("expected snapshot \"negated\" not to match {:?}", path));
+    }
+
+    #[test]
+    fn test_display_snapshot_matches() {
+        let (_dir, path) = scratch("display");
+        let test = Snapshot::new(String::from("display"), path.clone(), true).using_display();
+        assert!(!test.matches(&"line one\nline two"));
+        test.failure_message(&"line one\nline two");
+        assert!(test.matches(&"line one\nline two"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "line one\nline two\n");
+        assert!(!test.matches(&"line one"));
+    }
+}
@@ -0,0 +1,280 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use dsl::Matcher;
+
+const MAX_LISTED: usize = 16;
+
+// Returns the index of the first element that is out of order with respect to its predecessor.
+fn first_unordered<'a, I, T, F>(actual: &'a I, in_order: F) -> Option<usize>
+where &'a I: IntoIterator<Item=&'a T>, T: 'a, F: Fn(&T, &T) -> bool {
+    let mut iter = actual.into_iter();
+    let mut prev = iter.next()?;
+
+    for (idx, next) in iter.enumerate() {
+        if !in_order(prev, next) {
+            return Some(idx + 1)
+        }
+        prev = next;
+    }
+
+    None
+}
+
+fn order_message<'a, I, T, F>(order: &str, actual: &'a I, in_order: F) -> String
+where &'a I: IntoIterator<Item=&'a T>, T: 'a + Debug, F: Fn(&T, &T) -> bool {
+    match first_unordered(actual, in_order) {
+        Some(idx) => {
+            let elements: Vec<&T> = actual.into_iter().skip(idx - 1).take(2).collect();
+            format!("expected to be sorted {}, order breaks at index {}: {:?} followed by {:?}",
+                    order, idx, elements[0], elements[1])
+        },
+        None => format!("expected to be sorted {}", order),
+    }
+}
+
+#[derive(Debug)]
+pub enum SortedMatcher {
+    Ascending,
+    Descending,
+    StrictlyIncreasing,
+}
+
+impl SortedMatcher {
+    fn order(&self) -> &'static str {
+        match *self {
+            SortedMatcher::Ascending => "in ascending order",
+            SortedMatcher::Descending => "in descending order",
+            SortedMatcher::StrictlyIncreasing => "in strictly increasing order",
+        }
+    }
+
+    fn in_order<T: PartialOrd>(&self, a: &T, b: &T) -> bool {
+        match *self {
+            SortedMatcher::Ascending => a <= b,
+            SortedMatcher::Descending => a >= b,
+            SortedMatcher::StrictlyIncreasing => a < b,
+        }
+    }
+}
+
+impl<I, T: Debug + PartialOrd> Matcher<I> for SortedMatcher
+where for<'a> &'a I: IntoIterator<Item=&'a T> {
+    fn matches(&self, actual: &I) -> bool {
+        first_unordered(actual, |a, b| self.in_order(a, b)).is_none()
+    }
+
+    fn failure_message(&self, actual: &I) -> String {
+        order_message(self.order(), actual, |a, b| self.in_order(a, b))
+    }
+
+    fn negated_failure_message(&self, _actual: &I) -> String {
+        format!("expected not to be sorted {}", self.order())
+    }
+}
+
+pub struct SortedBy<F> {
+    compare: F,
+}
+
+impl<F> SortedBy<F> {
+    pub fn new(compare: F) -> SortedBy<F> {
+        SortedBy {
+            compare,
+        }
+    }
+}
+
+impl<I, T: Debug, F> Matcher<I> for SortedBy<F>
+where for<'a> &'a I: IntoIterator<Item=&'a T>, F: Fn(&T, &T) -> Ordering {
+    fn matches(&self, actual: &I) -> bool {
+        first_unordered(actual, |a, b| (self.compare)(a, b) != Ordering::Greater).is_none()
+    }
+
+    fn failure_message(&self, actual: &I) -> String {
+        order_message("by comparator", actual, |a, b| (self.compare)(a, b) != Ordering::Greater)
+    }
+
+    fn negated_failure_message(&self, _actual: &I) -> String {
+        String::from("expected not to be sorted by comparator")
+    }
+}
+
+pub struct SortedByKey<F> {
+    key: F,
+}
+
+impl<F> SortedByKey<F> {
+    pub fn new(key: F) -> SortedByKey<F> {
+        SortedByKey {
+            key,
+        }
+    }
+}
+
+impl<I, T: Debug, K: PartialOrd, F> Matcher<I> for SortedByKey<F>
+where for<'a> &'a I: IntoIterator<Item=&'a T>, F: Fn(&T) -> K {
+    fn matches(&self, actual: &I) -> bool {
+        first_unordered(actual, |a, b| (self.key)(a) <= (self.key)(b)).is_none()
+    }
+
+    fn failure_message(&self, actual: &I) -> String {
+        order_message("by key", actual, |a, b| (self.key)(a) <= (self.key)(b))
+    }
+
+    fn negated_failure_message(&self, _actual: &I) -> String {
+        String::from("expected not to be sorted by key")
+    }
+}
+
+#[derive(Debug)]
+pub struct UniqueElements;
+
+impl UniqueElements {
+    // Groups of indices sharing the same element, for every element occurring more than once, in
+    // order of first occurrence.
+    fn duplicates<'a, I, T>(&self, actual: &'a I) -> Vec<(&'a T, Vec<usize>)>
+    where &'a I: IntoIterator<Item=&'a T>, T: 'a + Eq + Hash {
+        let mut groups: Vec<(&T, Vec<usize>)> = Vec::new();
+        let mut group_of: HashMap<&T, usize> = HashMap::new();
+        for (idx, element) in actual.into_iter().enumerate() {
+            let group = *group_of.entry(element).or_insert_with(|| {
+                groups.push((element, Vec::new()));
+                groups.len() - 1
+            });
+            groups[group].1.push(idx);
+        }
+        groups.retain(|(_, indices)| indices.len() > 1);
+        groups
+    }
+}
+
+impl<I, T: Debug + Eq + Hash> Matcher<I> for UniqueElements
+where for<'a> &'a I: IntoIterator<Item=&'a T> {
+    fn matches(&self, actual: &I) -> bool {
+        let mut seen = HashSet::new();
+        actual.into_iter().all(|element| seen.insert(element))
+    }
+
+    fn failure_message(&self, actual: &I) -> String {
+        let duplicates = self.duplicates(actual);
+        let mut listed: Vec<String> = duplicates.iter()
+            .take(MAX_LISTED)
+            .map(|&(e, ref indices)| format!("{:?} at indices {:?}", e, indices))
+            .collect();
+        if duplicates.len() > MAX_LISTED {
+            listed.push(format!("and {} more", duplicates.len() - MAX_LISTED));
+        }
+        format!("expected unique elements, found duplicates: {}", listed.join(", "))
+    }
+
+    fn negated_failure_message(&self, _actual: &I) -> String {
+        String::from("expected duplicate elements, found all elements unique")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use super::*;
+
+    #[test]
+    fn test_ascending_matches() {
+        let test = SortedMatcher::Ascending;
+        assert!(test.matches(&vec![1, 2, 2, 3]));
+        assert!(test.matches(&Vec::<u8>::new()));
+        assert!(!test.matches(&vec![1, 3, 2]));
+    }
+
+    #[test]
+    fn test_ascending_failure_msg() {
+        let test = SortedMatcher::Ascending;
+        let msg = test.failure_message(&vec![1, 3, 2, 4]);
+        assert_eq!(msg, String::from("expected to be sorted in ascending order, \
+                                      order breaks at index 2: 3 followed by 2"));
+    }
+
+    #[test]
+    fn test_ascending_negated_failure_msg() {
+        let test = SortedMatcher::Ascending;
+        let msg = test.negated_failure_message(&vec![1, 2]);
+        assert_eq!(msg, String::from("expected not to be sorted in ascending order"));
+    }
+
+    #[test]
+    fn test_descending_matches() {
+        let test = SortedMatcher::Descending;
+        assert!(test.matches(&vec![3, 2, 2, 1]));
+        assert!(!test.matches(&vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn test_strictly_increasing_failure_msg() {
+        let test = SortedMatcher::StrictlyIncreasing;
+        let msg = test.failure_message(&vec![1, 2, 2]);
+        assert_eq!(msg, String::from("expected to be sorted in strictly increasing order, \
+                                      order breaks at index 2: 2 followed by 2"));
+    }
+
+    #[test]
+    fn test_sorted_by_matches() {
+        let test = SortedBy::new(|a: &i32, b: &i32| b.cmp(a));
+        assert!(test.matches(&vec![3, 2, 1]));
+        assert!(!test.matches(&vec![1, 2]));
+    }
+
+    #[test]
+    fn test_sorted_by_failure_msg() {
+        let test = SortedBy::new(|a: &i32, b: &i32| b.cmp(a));
+        let msg = test.failure_message(&vec![3, 4]);
+        assert_eq!(msg, String::from("expected to be sorted by comparator, \
+                                      order breaks at index 1: 3 followed by 4"));
+    }
+
+    #[test]
+    fn test_sorted_by_key_matches() {
+        let test = SortedByKey::new(|s: &&str| s.len());
+        assert!(test.matches(&vec!["a", "bb", "ccc"]));
+        assert!(!test.matches(&vec!["bb", "a"]));
+    }
+
+    #[test]
+    fn test_sorted_by_key_failure_msg() {
+        let test = SortedByKey::new(|s: &&str| s.len());
+        let msg = test.failure_message(&vec!["a", "ccc", "bb"]);
+        assert_eq!(msg, String::from("expected to be sorted by key, \
+                                      order breaks at index 2: \"ccc\" followed by \"bb\""));
+    }
+
+    #[test]
+    fn test_unique_matches() {
+        let test = UniqueElements;
+        assert!(test.matches(&vec![1, 2, 3]));
+        assert!(!test.matches(&vec![1, 2, 1]));
+    }
+
+    #[test]
+    fn test_unique_failure_msg() {
+        let test = UniqueElements;
+        let msg = test.failure_message(&vec![1, 2, 1, 3, 2, 1]);
+        assert_eq!(msg, String::from("expected unique elements, found duplicates: \
+                                      1 at indices [0, 2, 5], 2 at indices [1, 4]"));
+    }
+
+    #[test]
+    fn test_unique_many_duplicates_failure_msg() {
+        let test = UniqueElements;
+        let actual: Vec<u32> = (0..20).chain(0..20).collect();
+        let msg = test.failure_message(&actual);
+        assert!(msg.starts_with("expected unique elements, found duplicates: 0 at indices [0, 20], "));
+        assert!(msg.ends_with("15 at indices [15, 35], and 4 more"));
+    }
+
+    #[test]
+    fn test_unique_negated_failure_msg() {
+        let test = UniqueElements;
+        let msg = test.negated_failure_message(&vec![1, 2]);
+        assert_eq!(msg, String::from("expected duplicate elements, found all elements unique"));
+    }
+}
@@ -0,0 +1,353 @@
+use dsl::Matcher;
+use dsl::matchers::Number;
+use stats::{chi_square_p_value, mean, percentile, std_dev};
+
+/// A collection of numeric samples the statistical matchers can summarize.
+pub trait Samples {
+    /// The samples as floats, in order.
+    fn samples(&self) -> Vec<f64>;
+}
+
+impl<T: Number> Samples for Vec<T> {
+    fn samples(&self) -> Vec<f64> {
+        self.iter().map(|x| x.to_f64()).collect()
+    }
+}
+
+impl<T: Number> Samples for &[T] {
+    fn samples(&self) -> Vec<f64> {
+        self.iter().map(|x| x.to_f64()).collect()
+    }
+}
+
+impl<T: Number, const N: usize> Samples for [T; N] {
+    fn samples(&self) -> Vec<f64> {
+        self.iter().map(|x| x.to_f64()).collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct MeanCloseTo {
+    expected: f64,
+    tolerance: f64,
+}
+
+impl MeanCloseTo {
+    pub fn new(expected: f64, tolerance: f64) -> MeanCloseTo {
+        MeanCloseTo {
+            expected,
+            tolerance,
+        }
+    }
+}
+
+impl<A: Samples> Matcher<A> for MeanCloseTo {
+    fn matches(&self, actual: &A) -> bool {
+        // An empty sample has a NaN mean, which is never close to anything.
+        (mean(&actual.samples()) - self.expected).abs() <= self.tolerance
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let samples = actual.samples();
+        format!("expected sample mean to be within {:?} of {:?}, found mean {:?} (n = {})",
+                self.tolerance, self.expected, mean(&samples), samples.len())
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let samples = actual.samples();
+        format!("expected sample mean not to be within {:?} of {:?}, found mean {:?} (n = {})",
+                self.tolerance, self.expected, mean(&samples), samples.len())
+    }
+}
+
+#[derive(Debug)]
+pub struct StdDevBelow {
+    limit: f64,
+}
+
+impl StdDevBelow {
+    pub fn new(limit: f64) -> StdDevBelow {
+        StdDevBelow {
+            limit,
+        }
+    }
+}
+
+impl<A: Samples> Matcher<A> for StdDevBelow {
+    fn matches(&self, actual: &A) -> bool {
+        // Fewer than two samples have no standard deviation, which never matches.
+        std_dev(&actual.samples()) < self.limit
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let samples = actual.samples();
+        format!("expected sample standard deviation to be below {:?}, found {:?} (mean {:?}, n = {})",
+                self.limit, std_dev(&samples), mean(&samples), samples.len())
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let samples = actual.samples();
+        format!("expected sample standard deviation not to be below {:?}, found {:?} (mean {:?}, n = {})",
+                self.limit, std_dev(&samples), mean(&samples), samples.len())
+    }
+}
+
+#[derive(Debug)]
+pub struct HavePercentile<M> {
+    p: f64,
+    matcher: M,
+}
+
+impl<M> HavePercentile<M> {
+    pub fn new(p: f64, matcher: M) -> HavePercentile<M> {
+        assert!((0.0..=100.0).contains(&p), "percentile must be between 0 and 100, got {:?}", p);
+        HavePercentile {
+            p,
+            matcher,
+        }
+    }
+}
+
+impl<A: Samples, M: Matcher<f64>> Matcher<A> for HavePercentile<M> {
+    fn matches(&self, actual: &A) -> bool {
+        self.matcher.matches(&percentile(&actual.samples(), self.p))
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let samples = actual.samples();
+        let value = percentile(&samples, self.p);
+        format!("expected percentile {:?} of {} samples ({:?}) to match: {}",
+                self.p, samples.len(), value, self.matcher.failure_message(&value))
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let samples = actual.samples();
+        let value = percentile(&samples, self.p);
+        format!("expected percentile {:?} of {} samples ({:?}) not to match: {}",
+                self.p, samples.len(), value, self.matcher.negated_failure_message(&value))
+    }
+}
+
+const MAX_LISTED: usize = 16;
+
+// Below this many expected samples per bucket, the chi-square approximation of the statistic's
+// distribution is poor, so its p-value should not be trusted.
+const MIN_EXPECTED_PER_BUCKET: f64 = 5.0;
+
+// The outcome of a chi-square goodness-of-fit test against the uniform distribution.
+struct ChiSquare {
+    counts: Vec<usize>,
+    expected: f64,
+    statistic: f64,
+    p_value: f64,
+}
+
+#[derive(Debug)]
+pub struct UniformlyDistributed {
+    buckets: usize,
+    alpha: f64,
+}
+
+impl UniformlyDistributed {
+    pub fn new(buckets: usize, alpha: f64) -> UniformlyDistributed {
+        assert!(buckets >= 2, "a uniformity test needs at least 2 buckets, got {}", buckets);
+        assert!(alpha > 0.0 && alpha < 1.0, "significance level must be between 0 and 1, got {:?}", alpha);
+        UniformlyDistributed {
+            buckets,
+            alpha,
+        }
+    }
+
+    // The first sample that is not a bucket index, if any.
+    fn stray(&self, samples: &[f64]) -> Option<f64> {
+        samples.iter().cloned()
+            .find(|&x| !(x >= 0.0 && x < self.buckets as f64 && x.fract() == 0.0))
+    }
+
+    fn chi_square(&self, samples: &[f64]) -> ChiSquare {
+        let mut counts = vec![0; self.buckets];
+        for &x in samples {
+            counts[x as usize] += 1;
+        }
+        let expected = samples.len() as f64 / self.buckets as f64;
+        let statistic = counts.iter()
+            .map(|&observed| (observed as f64 - expected).powi(2) / expected)
+            .sum();
+        ChiSquare {
+            p_value: chi_square_p_value(statistic, self.buckets - 1),
+            counts,
+            expected,
+            statistic,
+        }
+    }
+
+    fn describe(&self, samples: &[f64]) -> String {
+        if samples.is_empty() {
+            return String::from("there are no samples")
+        }
+        if let Some(stray) = self.stray(samples) {
+            return format!("sample {:?} is not a bucket index", stray)
+        }
+        let test = self.chi_square(samples);
+        let mut description = format!("chi-square statistic {:.4} with {} degrees of freedom has p-value {:.4e}; \
+                                       bucket counts {:?}",
+                                      test.statistic, self.buckets - 1, test.p_value,
+                                      &test.counts[..test.counts.len().min(MAX_LISTED)]);
+        if test.counts.len() > MAX_LISTED {
+            description.push_str(&format!(" and {} more", test.counts.len() - MAX_LISTED));
+        }
+        if test.expected < MIN_EXPECTED_PER_BUCKET {
+            description.push_str(&format!(" (only {:?} samples expected per bucket, fewer than {:?}, \
+                                           so the result is unreliable; use more samples or fewer buckets)",
+                                          test.expected, MIN_EXPECTED_PER_BUCKET));
+        }
+        description
+    }
+}
+
+impl<A: Samples> Matcher<A> for UniformlyDistributed {
+    fn matches(&self, actual: &A) -> bool {
+        let samples = actual.samples();
+        !samples.is_empty() && self.stray(&samples).is_none() && self.chi_square(&samples).p_value >= self.alpha
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        let samples = actual.samples();
+        format!("expected {} samples to be uniformly distributed over {} buckets (alpha {:?}), but {}",
+                samples.len(), self.buckets, self.alpha, self.describe(&samples))
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        let samples = actual.samples();
+        format!("expected {} samples not to be uniformly distributed over {} buckets (alpha {:?}), but {}",
+                samples.len(), self.buckets, self.alpha, self.describe(&samples))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dsl::Matcher;
+    use dsl::matchers::{InRange, NumericMatcher};
+    use super::*;
+
+    #[test]
+    fn test_mean_close_to_matches() {
+        let test = MeanCloseTo::new(5.0, 0.1);
+        assert!(test.matches(&vec![4.0, 5.0, 6.0]));
+        assert!(test.matches(&[5u8, 5, 5]));
+        assert!(!test.matches(&vec![4.0, 5.0, 7.0]));
+        assert!(!test.matches(&Vec::<f64>::new()));
+    }
+
+    #[test]
+    fn test_mean_close_to_failure_msg() {
+        let msg = MeanCloseTo::new(5.0, 0.1).failure_message(&vec![4.0, 5.0, 7.5]);
+        assert_eq!(msg, String::from("expected sample mean to be within 0.1 of 5.0, found mean 5.5 (n = 3)"));
+    }
+
+    #[test]
+    fn test_mean_close_to_negated_failure_msg() {
+        let msg = MeanCloseTo::new(5.0, 0.1).negated_failure_message(&vec![5.0, 5.0]);
+        assert_eq!(msg, String::from("expected sample mean not to be within 0.1 of 5.0, found mean 5.0 (n = 2)"));
+    }
+
+    #[test]
+    fn test_std_dev_below_matches() {
+        let test = StdDevBelow::new(1.0);
+        assert!(test.matches(&vec![10.0, 10.5, 9.5]));
+        assert!(!test.matches(&vec![0, 10, 20]));
+        assert!(!test.matches(&vec![10.0]));
+    }
+
+    #[test]
+    fn test_std_dev_below_failure_msg() {
+        let msg = StdDevBelow::new(1.0).failure_message(&vec![2, 4, 6]);
+        assert_eq!(msg, String::from("expected sample standard deviation to be below 1.0, found 2.0 (mean 4.0, n = 3)"));
+    }
+
+    #[test]
+    fn test_percentile_matches() {
+        let latencies = vec![12, 15, 11, 80, 14, 13, 12, 16, 15, 14];
+        assert!(HavePercentile::new(50.0, InRange::new(10.0..20.0)).matches(&latencies));
+        assert!(!HavePercentile::new(99.0, InRange::new(10.0..20.0)).matches(&latencies));
+        assert!(!HavePercentile::new(50.0, NumericMatcher::Finite).matches(&Vec::<f64>::new()));
+        assert!(!HavePercentile::new(50.0, NumericMatcher::Finite).matches(&vec![1.0, f64::NAN, 3.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "percentile must be between 0 and 100")]
+    fn test_percentile_out_of_range() {
+        HavePercentile::new(101.0, NumericMatcher::<f64>::Finite);
+    }
+
+    #[test]
+    fn test_percentile_failure_msg() {
+        let test = HavePercentile::new(90.0, InRange::new(..20.0));
+        let msg = test.failure_message(&vec![15.0, 20.0, 35.0, 40.0, 50.0]);
+        assert_eq!(msg, String::from("expected percentile 90.0 of 5 samples (46.0) to match: \
+                                      expected 46.0 to be in range (-∞, 20.0), but it is above the upper bound 20.0"));
+    }
+
+    #[test]
+    fn test_percentile_negated_failure_msg() {
+        let test = HavePercentile::new(50.0, NumericMatcher::Positive);
+        let msg = test.negated_failure_message(&vec![1, 2, 3]);
+        assert_eq!(msg, String::from("expected percentile 50.0 of 3 samples (2.0) not to match: \
+                                      expected a non-positive number, found 2.0"));
+    }
+
+    #[test]
+    fn test_uniformly_distributed_matches() {
+        let test = UniformlyDistributed::new(4, 0.05);
+        let even: Vec<usize> = (0..400).map(|i| i % 4).collect();
+        assert!(test.matches(&even));
+        let skewed: Vec<usize> = (0..400).map(|i| if i % 2 == 0 { 0 } else { i % 4 }).collect();
+        assert!(!test.matches(&skewed));
+        assert!(!test.matches(&vec![0, 1, 2, 4]));
+        assert!(!test.matches(&vec![0.5, 1.0]));
+        assert!(!test.matches(&Vec::<u8>::new()));
+    }
+
+    #[test]
+    fn test_uniformly_distributed_failure_msg() {
+        let samples: Vec<u32> = vec![0, 0, 0, 0, 0, 0, 0, 1, 2, 3];
+        let msg = UniformlyDistributed::new(4, 0.05).failure_message(&samples);
+        assert_eq!(msg, String::from("expected 10 samples to be uniformly distributed over 4 buckets (alpha 0.05), \
+                                      but chi-square statistic 10.8000 with 3 degrees of freedom has p-value 1.2858e-2; \
+                                      bucket counts [7, 1, 1, 1] (only 2.5 samples expected per bucket, fewer than 5.0, \
+                                      so the result is unreliable; use more samples or fewer buckets)"));
+
+        let msg = UniformlyDistributed::new(2, 0.05).failure_message(&vec![0, 1, 2]);
+        assert_eq!(msg, String::from("expected 3 samples to be uniformly distributed over 2 buckets (alpha 0.05), \
+                                      but sample 2.0 is not a bucket index"));
+    }
+
+    #[test]
+    fn test_uniformly_distributed_negated_failure_msg() {
+        let msg = UniformlyDistributed::new(2, 0.05).negated_failure_message(&vec![0, 1, 0, 1]);
+        assert_eq!(msg, String::from("expected 4 samples not to be uniformly distributed over 2 buckets (alpha 0.05), \
+                                      but chi-square statistic 0.0000 with 1 degrees of freedom has p-value 1.0000e0; \
+                                      bucket counts [2, 2] (only 2.0 samples expected per bucket, fewer than 5.0, \
+                                      so the result is unreliable; use more samples or fewer buckets)"));
+
+        let samples: Vec<usize> = (0..20).map(|i| i % 2).collect();
+        let msg = UniformlyDistributed::new(2, 0.05).negated_failure_message(&samples);
+        assert_eq!(msg, String::from("expected 20 samples not to be uniformly distributed over 2 buckets (alpha 0.05), \
+                                      but chi-square statistic 0.0000 with 1 degrees of freedom has p-value 1.0000e0; \
+                                      bucket counts [10, 10]"));
+    }
+
+    #[test]
+    fn test_uniformly_distributed_many_buckets_failure_msg() {
+        let samples: Vec<usize> = (0..2000).map(|i| i % 20).collect();
+        let msg = UniformlyDistributed::new(20, 0.05).negated_failure_message(&samples);
+        assert!(msg.ends_with("bucket counts [100, 100, 100, 100, 100, 100, 100, 100, \
+                               100, 100, 100, 100, 100, 100, 100, 100] and 4 more"));
+    }
+
+    #[test]
+    #[should_panic(expected = "significance level must be between 0 and 1")]
+    fn test_uniformly_distributed_alpha_out_of_range() {
+        UniformlyDistributed::new(4, 1.0);
+    }
+}
@@ -11,6 +11,10 @@
 mod matchers;
 mod assertions;
 
+use std::borrow::Cow;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration as StdDuration;
 use time::Duration;
 use dsl::assertions::*;
 use dsl::matchers::*;
@@ -51,6 +55,37 @@ pub trait Matcher<A> {
     fn failure_message(&self, actual: &A) -> String;
     /// The megative failure message
     fn negated_failure_message(&self, actual: &A) -> String;
+    /// The failure message when the actual value is the field at `path` of an enclosing value
+    ///
+    /// Field matchers extend the path with their own field names, other matchers name the field
+    /// before their usual message.
+    fn field_failure_message(&self, path: &str, actual: &A, negated: bool) -> String {
+        if negated {
+            format!("expected field `{}` not to match: {}", path, self.negated_failure_message(actual))
+        } else {
+            format!("expected field `{}` to match: {}", path, self.failure_message(actual))
+        }
+    }
+}
+
+/// Boxed matchers delegate to the matcher they contain, so that matchers of different types can
+/// be collected as `Box<dyn Matcher<A>>`.
+impl<A, M: Matcher<A> + ?Sized> Matcher<A> for Box<M> {
+    fn matches(&self, actual: &A) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        (**self).failure_message(actual)
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        (**self).negated_failure_message(actual)
+    }
+
+    fn field_failure_message(&self, path: &str, actual: &A, negated: bool) -> String {
+        (**self).field_failure_message(path, actual, negated)
+    }
 }
 
 
@@ -59,6 +94,14 @@ pub fn expect<'a, A>(actual: &'a A) -> Expect<'a, A> {
     Expect::new(actual)
 }
 
+/// Expectation on the outcome of running the given closure.
+///
+/// The closure is run immediately and any panic is caught, without being printed, so that it
+/// can be asserted on with the panic matchers.
+pub fn expect_fn<F, R>(f: F) -> ExpectFn where F: FnOnce() -> R {
+    ExpectFn::new(f)
+}
+
 /// Asynchronous assertion that asserts the given closure **eventually** returns a value
 /// that satisfies the given matcher.
 pub fn eventually<F, A>(f: F) -> Async<A> where F: 'static + Fn() -> A {
@@ -92,6 +135,52 @@ pub fn equal<'a, E>(expected: &'a E) -> Equals<'a, E> {
     Equals::new(expected)
 }
 
+/// Equality matcher that takes the expected value by value, so it can be built from temporaries
+/// and returned from helper functions.
+///
+/// Uses the PartialEq trait between the actual and the expected type, so values of different
+/// types can be compared, such as a `String` with a `&str` or a `Vec` with a slice. Use
+/// `equal_deref` to compare the value behind a `Box` or other smart pointer.
+///
+/// ```
+/// use ferrous::dsl::*;
+///
+/// expect(&String::from("abc")).to(equal_value("abc"));
+/// expect(&vec![1, 2]).to(equal_value(&[1, 2][..]));
+/// expect(&5u8).to(equal_value(5));
+/// ```
+pub fn equal_value<E>(expected: E) -> EqualsValue<E> {
+    EqualsValue::new(expected)
+}
+
+/// Equality matcher for smart pointers such as `Box`, `Rc` and `String`, comparing the value they
+/// point to with the expected value.
+///
+/// ```
+/// use std::rc::Rc;
+/// use ferrous::dsl::*;
+///
+/// expect(&Box::new(5)).to(equal_deref(5));
+/// expect(&Rc::new(String::from("abc"))).to(equal_deref("abc"));
+/// ```
+pub fn equal_deref<E>(expected: E) -> EqualsDeref<E> {
+    EqualsDeref::new(expected)
+}
+
+/// Equality matcher that compares the actual value with the expected value using the given
+/// function, for types without a suitable PartialEq implementation.
+///
+/// The function receives the actual and the expected value, in that order.
+pub fn equal_by<A, E, F>(expected: E, comparator: F) -> EqualBy<E, F> where F: Fn(&A, &E) -> bool {
+    EqualBy::new(expected, comparator)
+}
+
+/// Equality matcher that compares the keys the given function extracts from the actual and
+/// expected values. Failure messages show both keys.
+pub fn equal_by_key<E, F>(expected: E, key: F) -> EqualByKey<E, F> {
+    EqualByKey::new(expected, key)
+}
+
 /// Container matcher that asserts a given element exists within an iterator.
 ///
 /// Uses the PartialEq trait.
@@ -99,11 +188,83 @@ pub fn contain<'a, E>(expected: &'a E) -> Contain<'a, E> {
     Contain::new(expected)
 }
 
+/// Matcher that asserts the elements of an iterator are in ascending order.
+///
+/// Uses the PartialOrd trait, allowing equal neighbours.
+pub fn be_sorted() -> SortedMatcher {
+    SortedMatcher::Ascending
+}
+
+/// Matcher that asserts the elements of an iterator are in descending order.
+pub fn be_sorted_descending() -> SortedMatcher {
+    SortedMatcher::Descending
+}
+
+/// Matcher that asserts the elements of an iterator are in strictly increasing order.
+pub fn be_strictly_increasing() -> SortedMatcher {
+    SortedMatcher::StrictlyIncreasing
+}
+
+/// Matcher that asserts the elements of an iterator are sorted according to the given comparator.
+pub fn be_sorted_by<F>(compare: F) -> SortedBy<F> {
+    SortedBy::new(compare)
+}
+
+/// Matcher that asserts the elements of an iterator are sorted by the key extracted with the
+/// given function.
+pub fn be_sorted_by_key<F>(key: F) -> SortedByKey<F> {
+    SortedByKey::new(key)
+}
+
+/// Matcher that asserts an iterator contains no duplicate elements.
+///
+/// Uses the Eq and Hash traits.
+pub fn have_unique_elements() -> UniqueElements {
+    UniqueElements
+}
+
+/// Byte equality matcher for `&[u8]`, `Vec<u8>` and byte arrays.
+///
+/// On failure, renders a side-by-side hex and ASCII dump of the first rows that differ, with the
+/// differing bytes bracketed.
+pub fn equal_bytes<'a, E: AsRef<[u8]> + ?Sized>(expected: &'a E) -> BytesMatcher<'a> {
+    BytesMatcher::Equal(Cow::Borrowed(expected.as_ref()))
+}
+
+/// Byte equality matcher against a string of hex digits, such as `"de ad be ef"`.
+///
+/// Whitespace between digits is ignored.
+///
+/// # Panics
+///
+/// Panics if the string is not valid hex.
+pub fn equal_hex(hex: &str) -> BytesMatcher<'static> {
+    match parse_hex(hex) {
+        Ok(bytes) => BytesMatcher::Equal(Cow::Owned(bytes)),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Matcher that asserts a byte sequence starts with the given bytes.
+pub fn start_with_bytes<'a, E: AsRef<[u8]> + ?Sized>(expected: &'a E) -> BytesMatcher<'a> {
+    BytesMatcher::StartWith(expected.as_ref())
+}
+
+/// Matcher that asserts a byte sequence contains the given bytes as a contiguous run.
+pub fn contain_bytes<'a, E: AsRef<[u8]> + ?Sized>(expected: &'a E) -> BytesMatcher<'a> {
+    BytesMatcher::Contain(expected.as_ref())
+}
+
 /// Matcher that asserts a value is a Some variant.
 pub fn be_some() -> OptionMatcher {
     OptionMatcher::SomeMatch
 }
 
+/// Matcher that asserts a value is a Some variant whose value satisfies the given matcher.
+pub fn be_some_with<M>(matcher: M) -> SomeWith<M> {
+    SomeWith::new(matcher)
+}
+
 /// Matcher that asserts a value is a None variant.
 pub fn be_none() -> OptionMatcher {
     OptionMatcher::NoneMatch
@@ -119,6 +280,45 @@ pub fn be_err() -> ResultMatcher {
     ResultMatcher::ErrMatch
 }
 
+/// Matcher that asserts a value is a Ok variant whose value satisfies the given matcher.
+pub fn be_ok_with<M>(matcher: M) -> OkWith<M> {
+    OkWith::new(matcher)
+}
+
+/// Matcher that asserts a value is a Err variant whose value satisfies the given matcher.
+pub fn be_err_with<M>(matcher: M) -> ErrWith<M> {
+    ErrWith::new(matcher)
+}
+
+/// Matcher that asserts a value is a Err variant whose error message satisfies the given matcher.
+///
+/// Works for boxed errors, such as `Box<dyn Error>`, and the standard library error types.
+pub fn be_err_with_message<M>(matcher: M) -> ErrWithMessage<M> {
+    ErrWithMessage::new(matcher)
+}
+
+/// Matcher that asserts one of the errors in the `source()` chain of an error has a message
+/// satisfying the given matcher.
+pub fn have_error_source_matching<M>(matcher: M) -> ErrorSourceMatching<M> {
+    ErrorSourceMatching::new(matcher)
+}
+
+/// Matcher that asserts an error, usually a `Box<dyn Error>`, downcasts to the error type `T`.
+pub fn be_error_of_type<T>() -> ErrorOfType<T> {
+    ErrorOfType::new()
+}
+
+/// Matcher that asserts an `io::Error`, or the error of an `io::Result`, has the given kind.
+pub fn be_io_error_kind(kind: ErrorKind) -> IoErrorMatcher {
+    IoErrorMatcher::Kind(kind)
+}
+
+/// Matcher that asserts an `io::Error`, or the error of an `io::Result`, has the given raw OS
+/// error code.
+pub fn be_os_error(code: i32) -> IoErrorMatcher {
+    IoErrorMatcher::OsError(code)
+}
+
 /// Matcher that asserts a value is true.
 pub fn be_true() -> BooleanMatcher {
     BooleanMatcher::TrueMatch
@@ -128,3 +328,414 @@ pub fn be_true() -> BooleanMatcher {
 pub fn be_false() -> BooleanMatcher {
     BooleanMatcher::FalseMatch
 }
+
+/// Matcher that asserts a map contains the given key.
+///
+/// Works for both `HashMap` and `BTreeMap`, using the map's own lookup. The key may be any
+/// borrowed form of the map's key type, such as `"a"` for `String` keys.
+pub fn have_key<'a, E: ?Sized>(key: &'a E) -> HaveKey<'a, E> {
+    HaveKey::new(key)
+}
+
+/// Matcher that asserts a map contains the given key with a value satisfying the given matcher.
+pub fn have_key_with_value<'a, E: ?Sized, M>(key: &'a E, matcher: M) -> HaveKeyWithValue<'a, E, M> {
+    HaveKeyWithValue::new(key, matcher)
+}
+
+/// Matcher that asserts a map contains all of the given key-value entries.
+pub fn have_entries<'a, K, V>(entries: &'a [(K, V)]) -> HaveEntries<'a, K, V> {
+    HaveEntries::new(entries)
+}
+
+/// Map equality matcher.
+///
+/// On failure, lists the missing keys, unexpected keys and keys whose values differ.
+pub fn equal_map<'a, M>(expected: &'a M) -> EqualMap<'a, M> {
+    EqualMap::new(expected)
+}
+
+/// Matcher that asserts every element of a set is contained in the given set.
+///
+/// Works for `HashSet` and `BTreeSet`, using hashed or ordered lookup, and for vectors, arrays
+/// and slices of hashable elements, in any combination. Elements of a `HashSet` are listed in
+/// failure messages sorted by their Debug rendering.
+pub fn be_subset_of<'a, E: ?Sized>(expected: &'a E) -> SetMatcher<'a, E> {
+    SetMatcher::SubsetOf(expected)
+}
+
+/// Matcher that asserts a set contains every element of the given set.
+pub fn be_superset_of<'a, E: ?Sized>(expected: &'a E) -> SetMatcher<'a, E> {
+    SetMatcher::SupersetOf(expected)
+}
+
+/// Matcher that asserts a set shares no elements with the given set.
+pub fn be_disjoint_from<'a, E: ?Sized>(expected: &'a E) -> SetMatcher<'a, E> {
+    SetMatcher::DisjointFrom(expected)
+}
+
+/// Matcher that asserts a set shares at least one element with the given set.
+pub fn intersect_with<'a, E: ?Sized>(expected: &'a E) -> SetMatcher<'a, E> {
+    SetMatcher::IntersectWith(expected)
+}
+
+/// Matcher that asserts a closure panics.
+pub fn panic() -> PanicMatcher {
+    PanicMatcher::Panic
+}
+
+/// Matcher that asserts a closure returns without panicking.
+pub fn not_panic() -> PanicMatcher {
+    PanicMatcher::NotPanic
+}
+
+/// Matcher that asserts a closure panics with a message satisfying the given matcher.
+pub fn panic_with_message<M>(matcher: M) -> PanicWithMessage<M> {
+    PanicWithMessage::new(matcher)
+}
+
+/// Matcher that asserts a closure panics with a payload of type `T`.
+pub fn panic_with_payload<T>() -> PanicWithPayload<T> {
+    PanicWithPayload::new()
+}
+
+/// Matcher that asserts the field extracted with the given accessor satisfies the given matcher.
+///
+/// Failure messages name the field, including the full path through nested field matchers.
+pub fn has_field<A, T, F, M>(name: &'static str, accessor: F, matcher: M) -> HasField<F, M>
+where F: Fn(&A) -> &T {
+    HasField::new(name, accessor, matcher)
+}
+
+/// Matcher that applies the given function to the actual value before matching the result.
+pub fn map<A, T, F, M>(f: F, matcher: M) -> Mapped<F, M> where F: Fn(&A) -> T {
+    Mapped::new(None, f, matcher)
+}
+
+/// Matcher that applies the given function to the actual value before matching the result,
+/// naming the transformation in failure messages.
+pub fn when_mapped<A, T, F, M>(description: &'static str, f: F, matcher: M) -> Mapped<F, M>
+where F: Fn(&A) -> T {
+    Mapped::new(Some(description), f, matcher)
+}
+
+/// Matcher that parses a string as `T` using the FromStr trait before matching the result.
+///
+/// A string that fails to parse does not match.
+pub fn after_parsing<T, M>(matcher: M) -> AfterParsing<T, M> {
+    AfterParsing::new(matcher)
+}
+
+/// Matcher that asserts a path exists.
+///
+/// Works for `Path`, `PathBuf`, `&str` and anything else implementing `AsRef<Path>`. Combine
+/// with `eventually` to wait for a file to appear.
+pub fn exist() -> PathMatcher {
+    PathMatcher::Exist
+}
+
+/// Matcher that asserts a path is a regular file, following symlinks.
+pub fn be_file() -> PathMatcher {
+    PathMatcher::File
+}
+
+/// Matcher that asserts a path is a directory, following symlinks.
+pub fn be_dir() -> PathMatcher {
+    PathMatcher::Dir
+}
+
+/// Matcher that asserts a path is a symlink.
+pub fn be_symlink() -> PathMatcher {
+    PathMatcher::Symlink
+}
+
+/// Matcher that asserts the contents of a file satisfy the given string matcher.
+pub fn have_contents<M>(matcher: M) -> HaveContents<M> {
+    HaveContents::new(matcher)
+}
+
+/// Matcher that asserts the size of a file in bytes satisfies the given matcher.
+pub fn have_size<M>(matcher: M) -> HaveSize<M> {
+    HaveSize::new(matcher)
+}
+
+/// Matcher that asserts a path has exactly the given unix permission bits, e.g. `0o644`.
+#[cfg(unix)]
+pub fn have_permissions(mode: u32) -> HavePermissions {
+    HavePermissions::new(mode)
+}
+
+/// Matcher that asserts a directory contains all of the given entries.
+///
+/// Entries are relative to the directory and may name nested paths.
+pub fn contain_entries<'a, E>(entries: &'a [E]) -> ContainEntries<'a, E> {
+    ContainEntries::new(entries)
+}
+
+/// Matcher that asserts a JSON string is structurally equal to the expected JSON.
+///
+/// Both sides are parsed, so whitespace and the order of object members are ignored. On
+/// failure, each difference is reported with its JSON pointer path.
+pub fn match_json(expected: &str) -> MatchJson {
+    MatchJson::new(expected)
+}
+
+/// Matcher that asserts the value at the given JSON pointer, such as `/a/0/b`, satisfies the
+/// given matcher.
+///
+/// The value is passed to the matcher rendered as compact JSON, so it can be checked with
+/// `match_json`.
+pub fn have_json_path<'a, M>(pointer: &'a str, matcher: M) -> HaveJsonPath<'a, M> {
+    HaveJsonPath::new(pointer, matcher)
+}
+
+/// Matcher that asserts a string or byte output equals the contents of a golden file.
+///
+/// Relative paths are resolved against the working directory, which is the package root under
+/// `cargo test`. When the expectation fails, a unified diff is printed for text, or a hex dump for
+/// binary data, and the actual output is written to a sibling `.actual` file, e.g.
+/// `expected.txt.actual`, so the golden file can be updated by copying it over. Call `normalizing_line_endings()` to treat
+/// `\r\n` and `\n` as equal.
+pub fn match_golden_file<P: AsRef<Path>>(path: P) -> GoldenFile {
+    GoldenFile::new(path.as_ref().to_path_buf())
+}
+
+/// Matcher that asserts a `std::time::Duration` is shorter than the given duration.
+///
+/// Durations are shown in human-readable form in failure messages, e.g. `1.5s`.
+pub fn be_shorter_than(limit: StdDuration) -> DurationMatcher {
+    DurationMatcher::ShorterThan(limit)
+}
+
+/// Matcher that asserts a `std::time::Duration` is longer than the given duration.
+pub fn be_longer_than(limit: StdDuration) -> DurationMatcher {
+    DurationMatcher::LongerThan(limit)
+}
+
+/// Starts a tolerance matcher: `be_within(tolerance).of(duration)` for durations, or
+/// `be_within(tolerance).of_now()` for a `SystemTime` or `Instant`.
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use ferrous::dsl::*;
+///
+/// expect(&Duration::from_millis(1050)).to(be_within(Duration::from_millis(100)).of(Duration::from_secs(1)));
+/// expect(&SystemTime::now()).to(be_within(Duration::from_secs(1)).of_now());
+/// ```
+pub fn be_within(tolerance: StdDuration) -> Within {
+    Within::new(tolerance)
+}
+
+/// Matcher that asserts a `SystemTime` or `Instant` is strictly before the given time.
+pub fn be_before<T>(time: T) -> TimeMatcher<T> {
+    TimeMatcher::Before(time)
+}
+
+/// Matcher that asserts a `SystemTime` or `Instant` is strictly after the given time.
+pub fn be_after<T>(time: T) -> TimeMatcher<T> {
+    TimeMatcher::After(time)
+}
+
+/// Matcher that asserts a value lies within the given range, such as `1..5`, `0.0..=1.0` or
+/// `..10`.
+///
+/// Accepts any `RangeBounds` over a PartialOrd type. Failure messages show the range in interval
+/// notation and which bound was crossed.
+pub fn be_in_range<R>(range: R) -> InRange<R> {
+    InRange::new(range)
+}
+
+/// Matcher that asserts a value equals one of the given candidates.
+pub fn be_one_of<'a, E>(candidates: &'a [E]) -> OneOf<'a, E> {
+    OneOf::new(candidates)
+}
+
+/// Matcher that asserts a value satisfies at least one of the given matchers.
+///
+/// Matchers of different types can be given boxed, as `Box<dyn Matcher<A>>`.
+///
+/// ```
+/// use ferrous::dsl::*;
+///
+/// let matchers: Vec<Box<dyn Matcher<u16>>> = vec![Box::new(equal_value(0)), Box::new(be_in_range(200..300))];
+/// expect(&204).to(be_one_of_matching(matchers));
+/// ```
+pub fn be_one_of_matching<M>(matchers: Vec<M>) -> OneOfMatching<M> {
+    OneOfMatching::new(matchers)
+}
+
+/// Matcher that asserts a number is greater than zero.
+///
+/// The numeric matchers work with all integer and float primitives.
+pub fn be_positive<T>() -> NumericMatcher<T> {
+    NumericMatcher::Positive
+}
+
+/// Matcher that asserts a number is less than zero. Negative zero is not negative.
+pub fn be_negative<T>() -> NumericMatcher<T> {
+    NumericMatcher::Negative
+}
+
+/// Matcher that asserts a number equals zero, including negative zero.
+pub fn be_zero<T>() -> NumericMatcher<T> {
+    NumericMatcher::Zero
+}
+
+/// Matcher that asserts a number is even. Floats must be integral to be even.
+pub fn be_even<T>() -> NumericMatcher<T> {
+    NumericMatcher::Even
+}
+
+/// Matcher that asserts a number is odd. Floats must be integral to be odd.
+pub fn be_odd<T>() -> NumericMatcher<T> {
+    NumericMatcher::Odd
+}
+
+/// Matcher that asserts a number is an exact multiple of `n`. Only zero is a multiple of zero.
+pub fn be_multiple_of<T>(n: T) -> NumericMatcher<T> {
+    NumericMatcher::MultipleOf(n)
+}
+
+/// Matcher that asserts a number is a positive power of two. For floats, negative powers such
+/// as `0.25` count too.
+pub fn be_power_of_two<T>() -> NumericMatcher<T> {
+    NumericMatcher::PowerOfTwo
+}
+
+/// Matcher that asserts a number is neither infinite nor NaN. Integers are always finite.
+pub fn be_finite<T>() -> NumericMatcher<T> {
+    NumericMatcher::Finite
+}
+
+/// Matcher that asserts a number is NaN.
+pub fn be_nan<T>() -> NumericMatcher<T> {
+    NumericMatcher::Nan
+}
+
+/// Matcher that asserts a number is positive or negative infinity.
+pub fn be_infinite<T>() -> NumericMatcher<T> {
+    NumericMatcher::Infinite
+}
+
+/// Matcher that asserts every element of float data is within an absolute tolerance of the
+/// corresponding expected element.
+///
+/// Works with one-dimensional data, such as `Vec<f64>` and slices, and two-dimensional data,
+/// such as `Vec<Vec<f64>>`, whose shapes must match. NaN never matches. Failure messages report
+/// the number of mismatches, the worst absolute and relative errors, and the first few
+/// mismatching cells.
+pub fn be_close_to_all<'a, E: ?Sized>(expected: &'a E, tolerance: f64) -> CloseToAll<'a, E> {
+    CloseToAll::new(expected, tolerance)
+}
+
+/// Matcher that asserts the mean of numeric samples is within a tolerance of the expected mean.
+pub fn have_mean_close_to(expected: f64, tolerance: f64) -> MeanCloseTo {
+    MeanCloseTo::new(expected, tolerance)
+}
+
+/// Matcher that asserts the sample standard deviation of numeric samples is below a limit.
+pub fn have_std_dev_below(limit: f64) -> StdDevBelow {
+    StdDevBelow::new(limit)
+}
+
+/// Matcher that asserts the `p`th percentile (0 to 100) of numeric samples matches a float
+/// matcher. Percentiles interpolate linearly between the closest ranks.
+///
+/// # Panics
+///
+/// Panics if `p` is not between 0 and 100.
+pub fn have_percentile<M: Matcher<f64>>(p: f64, matcher: M) -> HavePercentile<M> {
+    HavePercentile::new(p, matcher)
+}
+
+/// Matcher that asserts samples of bucket indices, from `0` to `buckets - 1`, are uniformly
+/// distributed. A chi-square goodness-of-fit test must not reject uniformity at significance
+/// level `alpha`; failure messages show the statistic, p-value and bucket counts.
+///
+/// # Panics
+///
+/// Panics if there are fewer than 2 buckets.
+pub fn be_uniformly_distributed_over(buckets: usize, alpha: f64) -> UniformlyDistributed {
+    UniformlyDistributed::new(buckets, alpha)
+}
+
+/// Matcher that formats a value with `Display` and applies a matcher to the resulting string.
+pub fn have_display<M>(matcher: M) -> HaveDisplay<M> {
+    HaveDisplay::new(matcher)
+}
+
+/// Matcher that formats a value with `Debug` (`{:?}`) and applies a matcher to the resulting
+/// string.
+pub fn have_debug<M>(matcher: M) -> HaveDebug<M> {
+    HaveDebug::new(false, matcher)
+}
+
+/// Matcher that formats a value with pretty `Debug` (`{:#?}`) and applies a matcher to the
+/// resulting string.
+pub fn have_pretty_debug<M>(matcher: M) -> HaveDebug<M> {
+    HaveDebug::new(true, matcher)
+}
+
+/// Matcher that asserts a value parses back to an equal value from its `Display` output.
+///
+/// Failure messages show the string the value was rendered as.
+pub fn round_trip_via_string() -> RoundTripViaString {
+    RoundTripViaString
+}
+
+/// Matcher that asserts a value decodes back to an equal value after being encoded.
+///
+/// `decode` receives the encoded form and returns a `Result`; failure messages show the encoded
+/// form alongside the decoded value or decoding error.
+pub fn round_trip_via<A, T, X, E, D>(encode: E, decode: D) -> RoundTrip<E, D>
+where E: Fn(&A) -> T, D: Fn(&T) -> Result<A, X> {
+    RoundTrip::new(encode, decode)
+}
+
+/// Matcher that asserts a value equals its clone.
+pub fn clone_equal() -> CloneEqual {
+    CloneEqual
+}
+
+/// Matcher that asserts `PartialEq` is reflexive, symmetric and transitive over a collection of
+/// sample values.
+///
+/// Failure messages report the counterexample using the fewest samples.
+pub fn have_lawful_eq() -> LawfulEq {
+    LawfulEq
+}
+
+/// Matcher that asserts samples that are equal also hash equally, over a collection of sample
+/// values.
+pub fn have_hash_consistent_with_eq() -> HashConsistentWithEq {
+    HashConsistentWithEq
+}
+
+/// Matcher that asserts `Ord` is total (agreeing with `partial_cmp`), antisymmetric, transitive
+/// and consistent with `PartialEq`, over a collection of sample values.
+///
+/// Failure messages report the counterexample using the fewest samples.
+pub fn have_lawful_ord() -> LawfulOrd {
+    LawfulOrd
+}
+
+#[doc(hidden)]
+pub fn __match_pattern<A, F>(pattern: &'static str, predicate: F) -> PatternMatcher<F>
+where F: Fn(&A) -> bool {
+    PatternMatcher::new(pattern, predicate)
+}
+
+#[doc(hidden)]
+pub fn __has_fields<'a, A>() -> HasFields<'a, A> {
+    HasFields::new()
+}
+
+#[doc(hidden)]
+pub fn __equal_ignoring_fields<A, E, F>(expected: E, fields: &'static [&'static str], with_ignored_fields: F)
+-> EqualIgnoringFields<E, F> where F: Fn(&A, &A) -> A {
+    EqualIgnoringFields::new(expected, fields, with_ignored_fields)
+}
+
+#[doc(hidden)]
+pub fn __match_snapshot(manifest_dir: &str, source_file: &str, name: Option<&str>) -> Snapshot {
+    Snapshot::beside(manifest_dir, source_file, name)
+}
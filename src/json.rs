@@ -0,0 +1,547 @@
+//! A small JSON parser used by the JSON matchers.
+//!
+//! Only what the matchers need is supported: parsing a document into a value tree, rendering it
+//! back in compact form, structural comparison and JSON pointer lookups.
+
+use std::collections::HashMap;
+use std::fmt;
+
+// Deeper documents are rejected rather than risking a stack overflow in the recursive parser.
+const MAX_DEPTH: usize = 128;
+
+/// A JSON number, kept as written so that large integers and long fractions compare exactly
+/// rather than after rounding to a float.
+#[derive(Debug, Clone)]
+pub struct Number {
+    text: String,
+}
+
+impl Number {
+    fn new(text: &str) -> Number {
+        Number {
+            text: text.to_owned(),
+        }
+    }
+
+    // The exact decimal value as (negative, significant digits, exponent), where the digits have
+    // no leading or trailing zeros. Zero has no digits and no sign.
+    fn decimal(&self) -> (bool, String, i128) {
+        let (negative, unsigned) = match self.text.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, &self.text[..]),
+        };
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(idx) => (&unsigned[..idx], &unsigned[idx + 1..]),
+            None => (unsigned, "0"),
+        };
+        // Exponents too large for i128 saturate, which still keeps distinct magnitudes apart.
+        let exponent = exponent.trim_start_matches('+').parse::<i128>().unwrap_or_else(|_| {
+            if exponent.starts_with('-') { i128::MIN / 2 } else { i128::MAX / 2 }
+        });
+        let (integer, fraction) = match mantissa.find('.') {
+            Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+            None => (mantissa, ""),
+        };
+
+        let digits = format!("{}{}", integer, fraction);
+        let significant = digits.trim_start_matches('0');
+        let trimmed = significant.trim_end_matches('0');
+        if trimmed.is_empty() {
+            return (false, String::new(), 0)
+        }
+        let trailing = (significant.len() - trimmed.len()) as i128;
+        (negative, trimmed.to_owned(), exponent - fraction.len() as i128 + trailing)
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Number) -> bool {
+        self.decimal() == other.decimal()
+    }
+}
+
+/// Renders the number as it was written.
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(input: &str) -> Result<Json, String> {
+        let mut parser = Parser {
+            input: input.as_bytes(),
+            pos: 0,
+            depth: 0,
+        };
+        parser.skip_whitespace();
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos < parser.input.len() {
+            return Err(parser.error("trailing characters"))
+        }
+        Ok(value)
+    }
+
+    fn get(&self, key: &str) -> Option<&Json> {
+        match *self {
+            Json::Object(ref members) => {
+                members.iter().find(|&(k, _)| k == key).map(|(_, v)| v)
+            },
+            Json::Array(ref elements) => {
+                key.parse::<usize>().ok().and_then(|idx| elements.get(idx))
+            },
+            _ => None,
+        }
+    }
+
+    /// Resolves an RFC 6901 JSON pointer, such as `/a/0/b`, against this value.
+    pub fn pointer(&self, pointer: &str) -> Option<&Json> {
+        if pointer.is_empty() {
+            return Some(self)
+        }
+        if !pointer.starts_with('/') {
+            return None
+        }
+
+        pointer[1..].split('/').try_fold(self, |value, token| {
+            value.get(&token.replace("~1", "/").replace("~0", "~"))
+        })
+    }
+
+    /// Structural differences between `self`, the expected value, and `actual`, as pairs of JSON
+    /// pointer and description. Object member order is ignored.
+    pub fn differences(&self, actual: &Json) -> Vec<(String, String)> {
+        let mut differences = Vec::new();
+        diff(self, actual, &mut String::new(), &mut differences);
+        differences
+    }
+}
+
+fn escape_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+// Members by key, keeping the first of any repeated key as `Json::get` does.
+fn index_members(members: &[(String, Json)]) -> HashMap<&str, &Json> {
+    let mut index = HashMap::with_capacity(members.len());
+    for (key, value) in members {
+        index.entry(key.as_str()).or_insert(value);
+    }
+    index
+}
+
+fn diff(expected: &Json, actual: &Json, path: &mut String, differences: &mut Vec<(String, String)>) {
+    match (expected, actual) {
+        (Json::Object(expected_members), Json::Object(actual_members)) => {
+            let expected_index = index_members(expected_members);
+            let actual_index = index_members(actual_members);
+            for (key, value) in expected_members {
+                let len = path.len();
+                path.push('/');
+                path.push_str(&escape_token(key));
+                match actual_index.get(key.as_str()) {
+                    Some(actual_value) => diff(value, actual_value, path, differences),
+                    None => differences.push((path.clone(), format!("expected {}, found nothing", value))),
+                }
+                path.truncate(len);
+            }
+            for (key, value) in actual_members {
+                if !expected_index.contains_key(key.as_str()) {
+                    let pointer = format!("{}/{}", path, escape_token(key));
+                    differences.push((pointer, format!("unexpected {}", value)));
+                }
+            }
+        },
+        (Json::Array(expected_elements), Json::Array(actual_elements)) => {
+            let len = expected_elements.len().max(actual_elements.len());
+            for idx in 0..len {
+                let pointer = format!("{}/{}", path, idx);
+                match (expected_elements.get(idx), actual_elements.get(idx)) {
+                    (Some(e), Some(a)) => {
+                        let mut path = pointer;
+                        diff(e, a, &mut path, differences);
+                    },
+                    (Some(e), None) => differences.push((pointer, format!("expected {}, found nothing", e))),
+                    (None, Some(a)) => differences.push((pointer, format!("unexpected {}", a))),
+                    (None, None) => {},
+                }
+            }
+        },
+        _ => {
+            if expected != actual {
+                differences.push((path.clone(), format!("expected {}, found {}", expected, actual)));
+            }
+        },
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// Renders the value as compact JSON.
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Number(ref n) => write!(f, "{}", n),
+            Json::String(ref s) => write_string(f, s),
+            Json::Array(ref elements) => {
+                write!(f, "[")?;
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "]")
+            },
+            Json::Object(ref members) => {
+                write!(f, "{{")?;
+                for (idx, (key, value)) in members.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, msg: &str) -> String {
+        format!("{} at offset {}", msg, self.pos)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).cloned()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &str, value: Json) -> Result<Json, String> {
+        if self.input[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            Err(self.error("invalid literal"))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json, String> {
+        match self.peek() {
+            Some(b'n') => self.expect_literal("null", Json::Null),
+            Some(b't') => self.expect_literal("true", Json::Bool(true)),
+            Some(b'f') => self.expect_literal("false", Json::Bool(false)),
+            Some(b'"') => self.parse_string().map(Json::String),
+            Some(b'[') | Some(b'{') => {
+                if self.depth == MAX_DEPTH {
+                    return Err(self.error("nesting too deep"))
+                }
+                self.depth += 1;
+                let value = if self.peek() == Some(b'[') { self.parse_array() } else { self.parse_object() };
+                self.depth -= 1;
+                value
+            },
+            Some(b'-') | Some(b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Json, String> {
+        self.pos += 1;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(elements))
+        }
+
+        loop {
+            self.skip_whitespace();
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(elements))
+                },
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Json, String> {
+        self.pos += 1;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(members))
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected string key"))
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            if self.peek() != Some(b':') {
+                return Err(self.error("expected ':'"))
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            let value = self.parse_value()?;
+            members.push((key, value));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(members))
+                },
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, String> {
+        // Checked up front, since `from_str_radix` also accepts a leading `+`.
+        let digits = self.input.get(self.pos..self.pos + 4)
+            .filter(|digits| digits.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|digits| ::std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok());
+        match digits {
+            Some(code) => {
+                self.pos += 4;
+                Ok(code)
+            },
+            None => Err(self.error("invalid unicode escape")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    break
+                },
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.pos += 1;
+                            let mut code = self.parse_hex4()?;
+                            if (0xd800..0xdc00).contains(&code) {
+                                if !self.input[self.pos..].starts_with(b"\\u") {
+                                    return Err(self.error("unpaired surrogate"))
+                                }
+                                self.pos += 2;
+                                let low = self.parse_hex4()?;
+                                if !(0xdc00..0xe000).contains(&low) {
+                                    return Err(self.error("invalid low surrogate"))
+                                }
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            let c = ::std::char::from_u32(code)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?;
+                            let mut buf = [0; 4];
+                            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                            continue
+                        },
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    self.pos += 1;
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                },
+                Some(b) if b < 0x20 => return Err(self.error("control character in string")),
+                Some(b) => {
+                    bytes.push(b);
+                    self.pos += 1;
+                },
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+
+        String::from_utf8(bytes).map_err(|_| self.error("invalid utf-8 in string"))
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn parse_number(&mut self) -> Result<Json, String> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        if self.peek() == Some(b'0') {
+            self.pos += 1;
+        } else if self.skip_digits() == 0 {
+            return Err(self.error("invalid number"))
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if self.skip_digits() == 0 {
+                return Err(self.error("invalid number"))
+            }
+        }
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.skip_digits() == 0 {
+                return Err(self.error("invalid number"))
+            }
+        }
+
+        // The lexeme is ASCII, having been checked digit by digit above.
+        let lexeme = ::std::str::from_utf8(&self.input[start..self.pos]).unwrap_or("");
+        Ok(Json::Number(Number::new(lexeme)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Json {
+        Json::parse(input).unwrap()
+    }
+
+    fn number(text: &str) -> Json {
+        Json::Number(Number::new(text))
+    }
+
+    #[test]
+    fn test_parse_values() {
+        assert_eq!(parse(" null "), Json::Null);
+        assert_eq!(parse("true"), Json::Bool(true));
+        assert_eq!(parse("-1.5e2"), number("-150"));
+        assert_eq!(parse("\"a\\n\\u00e9\\ud83d\\ude00\""), Json::String(String::from("a\né😀")));
+        assert_eq!(parse("[1, [], {}]"), Json::Array(vec![number("1"), Json::Array(vec![]),
+                                                          Json::Object(vec![])]));
+        assert_eq!(parse("{\"a\": {\"b\": null}}"),
+                   Json::Object(vec![(String::from("a"),
+                                      Json::Object(vec![(String::from("b"), Json::Null)]))]));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Json::parse("[1,]"), Err(String::from("unexpected character at offset 3")));
+        assert_eq!(Json::parse("{\"a\" 1}"), Err(String::from("expected ':' at offset 5")));
+        assert_eq!(Json::parse("01"), Err(String::from("trailing characters at offset 1")));
+        assert_eq!(Json::parse("\"abc"), Err(String::from("unterminated string at offset 4")));
+        assert_eq!(Json::parse(""), Err(String::from("unexpected end of input at offset 0")));
+        assert_eq!(Json::parse("\"\\u+041\""), Err(String::from("invalid unicode escape at offset 3")));
+    }
+
+    #[test]
+    fn test_parse_depth_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(Json::parse(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(Json::parse(&nested(MAX_DEPTH + 1)), Err(format!("nesting too deep at offset {}", MAX_DEPTH)));
+        assert!(Json::parse(&nested(100_000)).is_err());
+    }
+
+    #[test]
+    fn test_number_equality() {
+        assert_eq!(number("1"), number("1.0"));
+        assert_eq!(number("1"), number("1e0"));
+        assert_eq!(number("150"), number("1.5E+2"));
+        assert_eq!(number("0.25"), number("25e-2"));
+        assert_eq!(number("0"), number("-0.0"));
+        assert_ne!(number("9007199254740992"), number("9007199254740993"));
+        assert_ne!(number("0.1000000000000000001"), number("0.1"));
+        assert_ne!(number("-1"), number("1"));
+        assert_ne!(number("1e400"), number("1e401"));
+    }
+
+    #[test]
+    fn test_display() {
+        let json = parse("{ \"a\" : [1, 2.5, \"x\\\"y\"], \"b\": false }");
+        assert_eq!(json.to_string(), "{\"a\":[1,2.5,\"x\\\"y\"],\"b\":false}");
+        assert_eq!(parse("[1e400, -0.0, 12345678901234567890]").to_string(), "[1e400,-0.0,12345678901234567890]");
+    }
+
+    #[test]
+    fn test_pointer() {
+        let json = parse("{\"a\": [{\"b\": 1}], \"c/d\": 2, \"\": 3}");
+        assert_eq!(json.pointer("/a/0/b"), Some(&number("1")));
+        assert_eq!(json.pointer("/c~1d"), Some(&number("2")));
+        assert_eq!(json.pointer("/"), Some(&number("3")));
+        assert_eq!(json.pointer(""), Some(&json));
+        assert_eq!(json.pointer("/a/1"), None);
+        assert_eq!(json.pointer("a"), None);
+    }
+
+    #[test]
+    fn test_differences() {
+        let expected = parse("{\"a\": 1, \"b\": [1, 2], \"c\": true}");
+        let actual = parse("{\"b\": [1, 3, 4], \"a\": 1, \"d\": null}");
+        assert_eq!(expected.differences(&actual), vec![
+            (String::from("/b/1"), String::from("expected 2, found 3")),
+            (String::from("/b/2"), String::from("unexpected 4")),
+            (String::from("/c"), String::from("expected true, found nothing")),
+            (String::from("/d"), String::from("unexpected null")),
+        ]);
+        assert!(expected.differences(&parse("{\"c\": true, \"b\": [1, 2], \"a\": 1.0}")).is_empty());
+    }
+}
@@ -8,3 +8,7 @@
 extern crate time;
 
 pub mod dsl;
+
+mod json;
+mod diff;
+mod stats;
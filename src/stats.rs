@@ -0,0 +1,152 @@
+use std::f64::consts::PI;
+
+const EPSILON: f64 = 1e-15;
+const TINY: f64 = 1e-300;
+const MAX_ITERATIONS: usize = 1000;
+
+// Lanczos approximation with g = 7 and nine coefficients.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+pub fn mean(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+// The sample standard deviation, with Bessel's correction.
+pub fn std_dev(samples: &[f64]) -> f64 {
+    let mean = mean(samples);
+    let squares: f64 = samples.iter().map(|x| (x - mean) * (x - mean)).sum();
+    (squares / (samples.len() as f64 - 1.0)).sqrt()
+}
+
+// The `p`th percentile (0 to 100), interpolating linearly between the closest ranks. NaN when there
+// are no samples, or when any sample is NaN, since NaN has no rank.
+pub fn percentile(samples: &[f64], p: f64) -> f64 {
+    if samples.is_empty() || samples.iter().any(|x| x.is_nan()) {
+        return f64::NAN
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // Reflection formula, since the approximation only holds for the right half-plane.
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x)
+    }
+    let x = x - 1.0;
+    let t = x + LANCZOS_G + 0.5;
+    let series = LANCZOS_COEFFICIENTS.iter().enumerate().skip(1)
+        .fold(LANCZOS_COEFFICIENTS[0], |sum, (i, c)| sum + c / (x + i as f64));
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+// The regularized upper incomplete gamma function Q(a, x).
+fn upper_gamma(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0
+    }
+    let prefactor = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        // The series for P(a, x) converges quickly here.
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break
+            }
+        }
+        (1.0 - sum * prefactor).max(0.0)
+    } else {
+        // Continued fraction for Q(a, x), evaluated with the modified Lentz method.
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..MAX_ITERATIONS {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = b + an / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break
+            }
+        }
+        prefactor * h
+    }
+}
+
+// The probability of a chi-square statistic at least this large under the null hypothesis.
+pub fn chi_square_p_value(statistic: f64, degrees_of_freedom: usize) -> f64 {
+    upper_gamma(degrees_of_freedom as f64 / 2.0, statistic / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!((actual - expected).abs() <= tolerance, "expected {} to be close to {}", actual, expected);
+    }
+
+    #[test]
+    fn test_mean_and_std_dev() {
+        let samples = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_close(mean(&samples), 5.0, 1e-12);
+        assert_close(std_dev(&samples), 2.138_089_935_299_395, 1e-12);
+        assert!(mean(&[]).is_nan());
+        assert!(std_dev(&[1.0]).is_nan());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let samples = [15.0, 20.0, 35.0, 40.0, 50.0];
+        assert_close(percentile(&samples, 0.0), 15.0, 1e-12);
+        assert_close(percentile(&samples, 50.0), 35.0, 1e-12);
+        assert_close(percentile(&samples, 90.0), 46.0, 1e-12);
+        assert_close(percentile(&samples, 100.0), 50.0, 1e-12);
+        assert!(percentile(&[], 50.0).is_nan());
+        assert!(percentile(&[1.0, f64::NAN, 3.0], 0.0).is_nan());
+        assert!(percentile(&[1.0, 2.0, f64::NAN], 50.0).is_nan());
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_close(ln_gamma(1.0), 0.0, 1e-12);
+        assert_close(ln_gamma(5.0), 24f64.ln(), 1e-12);
+        assert_close(ln_gamma(0.5), PI.sqrt().ln(), 1e-12);
+    }
+
+    #[test]
+    fn test_chi_square_p_value() {
+        assert_close(chi_square_p_value(0.0, 3), 1.0, 1e-12);
+        assert_close(chi_square_p_value(3.841_458_820_694_124, 1), 0.05, 1e-9);
+        assert_close(chi_square_p_value(7.814_727_903_251_178, 3), 0.05, 1e-9);
+        assert_close(chi_square_p_value(2.0, 4), 0.735_758_882_342_884_6, 1e-9);
+        assert_close(chi_square_p_value(50.0, 10), 2.669_083_710_853_185e-7, 1e-12);
+    }
+}
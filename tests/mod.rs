@@ -1,7 +1,9 @@
+#[macro_use]
 extern crate ferrous;
 
 mod boolean;
 
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use ferrous::dsl::*;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -10,6 +12,14 @@ enum TestEnum {
     Fail
 }
 
+struct Seconds(f64);
+
+impl std::fmt::Display for Seconds {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}s", self.0)
+    }
+}
+
 #[test]
 fn test_expect_to() {
     let actual = 1;
@@ -179,3 +189,493 @@ fn test_consistently_should_not_err_panic() {
         actual.clone()
     }).should_not(be_err());
 }
+
+#[test]
+fn test_expect_to_have_key() {
+    let mut actual = HashMap::new();
+    actual.insert(String::from("pass"), TestEnum::Pass);
+    expect(&actual).to(have_key("pass"));
+    expect(&actual).to(have_key_with_value("pass", equal(&TestEnum::Pass)));
+}
+
+#[test]
+#[should_panic(expected="expected to find key \"fail\" in map, found keys [\"pass\"]")]
+fn test_expect_to_have_key_panic() {
+    let mut actual = HashMap::new();
+    actual.insert(String::from("pass"), TestEnum::Pass);
+    expect(&actual).to(have_key("fail"));
+}
+
+#[test]
+fn test_expect_to_equal_map() {
+    let mut actual = BTreeMap::new();
+    actual.insert(1, TestEnum::Pass);
+    let expected = actual.clone();
+    expect(&actual).to(equal_map(&expected));
+    expect(&actual).to(have_entries(&[(1, TestEnum::Pass)]));
+}
+
+#[test]
+#[should_panic(expected="expected maps to be equal, unexpected keys [2]")]
+fn test_expect_to_equal_map_panic() {
+    let mut actual = BTreeMap::new();
+    actual.insert(1, TestEnum::Pass);
+    let expected = actual.clone();
+    actual.insert(2, TestEnum::Fail);
+    expect(&actual).to(equal_map(&expected));
+}
+
+#[test]
+fn test_expect_to_be_subset_of() {
+    let actual: BTreeSet<&str> = ["read"].iter().cloned().collect();
+    let expected: HashSet<&str> = ["read", "write"].iter().cloned().collect();
+    expect(&actual).to(be_subset_of(&expected));
+    expect(&expected).to(be_superset_of(&actual));
+    expect(&actual).to(intersect_with(&expected));
+}
+
+#[test]
+#[should_panic(expected="expected to be disjoint, found common elements: [\"read\"]")]
+fn test_expect_to_be_disjoint_from_panic() {
+    let actual: BTreeSet<&str> = ["read", "exec"].iter().cloned().collect();
+    let expected: HashSet<&str> = ["read", "write"].iter().cloned().collect();
+    expect(&actual).to(be_disjoint_from(&expected));
+}
+
+#[test]
+fn test_expect_vec_to_be_subset_of_slice() {
+    let actual = vec!["read", "read"];
+    let expected = ["read", "write"];
+    expect(&actual).to(be_subset_of(&expected[..]));
+    expect(&actual).not_to(be_superset_of(&expected[..]));
+}
+
+#[test]
+fn test_expect_to_be_sorted() {
+    let actual = vec![1, 2, 2, 5];
+    expect(&actual).to(be_sorted());
+    expect(&actual).not_to(be_strictly_increasing());
+    expect(&actual).to(be_sorted_by_key(|x: &i32| -x / 10));
+}
+
+#[test]
+#[should_panic(expected="expected unique elements, found duplicates: 2 at indices [1, 2]")]
+fn test_expect_to_have_unique_elements_panic() {
+    let actual = vec![1, 2, 2, 5];
+    expect(&actual).to(have_unique_elements());
+}
+
+#[test]
+fn test_expect_to_be_ok_with() {
+    let actual: Result<u8, TestEnum> = Ok(5);
+    expect(&actual).to(be_ok_with(equal(&5)));
+    expect(&Some(TestEnum::Pass)).to(be_some_with(equal(&TestEnum::Pass)));
+}
+
+#[test]
+#[should_panic(expected="expected Err variant whose value matches: expected Pass to equal Fail, found Err(Fail)")]
+fn test_expect_to_be_err_with_panic() {
+    let actual: Result<u8, TestEnum> = Err(TestEnum::Fail);
+    expect(&actual).to(be_err_with(equal(&TestEnum::Pass)));
+}
+
+#[test]
+fn test_expect_fn_to_panic() {
+    expect_fn(|| panic!("boom")).to(panic());
+    expect_fn(|| panic!("boom")).to(panic_with_message(equal(&String::from("boom"))));
+    expect_fn(|| std::panic::panic_any(TestEnum::Fail)).to(panic_with_payload::<TestEnum>());
+    expect_fn(|| 1).to(not_panic());
+}
+
+#[test]
+#[should_panic(expected="expected closure not to panic, but it panicked with message \"boom\"")]
+fn test_expect_fn_not_to_panic_panic() {
+    expect_fn(|| panic!("boom")).to(not_panic());
+}
+
+#[test]
+fn test_expect_to_match_pattern() {
+    let actual: Result<TestEnum, TestEnum> = Ok(TestEnum::Pass);
+    expect(&actual).to(match_pattern!(Ok(TestEnum::Pass)));
+    expect(&Some(5)).to(match_pattern!(Some(n) if *n > 3));
+}
+
+#[test]
+#[should_panic(expected="expected Err(Fail) to match pattern `Ok(TestEnum::Pass) | Err(TestEnum::Pass)`")]
+fn test_expect_to_match_pattern_panic() {
+    let actual: Result<TestEnum, TestEnum> = Err(TestEnum::Fail);
+    expect(&actual).to(match_pattern!(Ok(TestEnum::Pass) | Err(TestEnum::Pass)));
+}
+
+#[derive(Debug, Clone)]
+struct Probe {
+    state: TestEnum,
+    count: usize,
+}
+
+#[test]
+fn test_expect_to_have_field() {
+    let actual = Probe { state: TestEnum::Pass, count: 1 };
+    expect(&actual).to(has_field("state", |p: &Probe| &p.state, equal(&TestEnum::Pass)));
+    expect(&actual).to(has_fields!(Probe { state: equal(&TestEnum::Pass), count: equal(&1) }));
+}
+
+#[test]
+#[should_panic(expected="expected field `state` to match: expected Pass to equal Fail")]
+fn test_eventually_should_have_field_panic() {
+    let actual = Probe { state: TestEnum::Fail, count: 1 };
+
+    eventually(move || {
+        actual.clone()
+    }).should(has_field("state", |p: &Probe| &p.state, equal(&TestEnum::Pass)));
+}
+
+#[test]
+fn test_expect_to_after_parsing() {
+    let actual = String::from("42");
+    expect(&actual).to(after_parsing::<u32, _>(equal(&42)));
+    expect(&actual).to(when_mapped("length", |s: &String| s.len(), equal(&2)));
+    expect(&vec![1, 2]).to(map(|v: &Vec<u8>| v.len(), equal(&2)));
+}
+
+#[test]
+#[should_panic(expected="expected \"4x\" to parse as u32, but parsing failed")]
+fn test_expect_to_after_parsing_panic() {
+    let actual = "4x";
+    expect(&actual).to(after_parsing::<u32, _>(equal(&42)));
+}
+
+#[test]
+fn test_expect_to_be_error_of_type() {
+    let actual: Result<u8, Box<dyn std::error::Error>> = "x".parse::<u8>().map_err(From::from);
+    expect(&actual).to(be_err_with(be_error_of_type::<std::num::ParseIntError>()));
+    expect(&actual).to(be_err_with_message(equal(&String::from("invalid digit found in string"))));
+}
+
+#[test]
+#[should_panic(expected="expected Err variant whose message matches: expected \"empty\" to equal \"invalid digit found in string\"")]
+fn test_expect_to_be_err_with_message_panic() {
+    let actual = "x".parse::<u8>();
+    expect(&actual).to(be_err_with_message(equal(&String::from("empty"))));
+}
+
+#[test]
+fn test_expect_to_be_io_error_kind() {
+    let actual = std::fs::File::open("/nonexistent/ferrous/file");
+    expect(&actual).to(be_io_error_kind(std::io::ErrorKind::NotFound));
+}
+
+#[test]
+#[should_panic(expected="expected io error of kind NotFound, found io error (kind: Other")]
+fn test_expect_to_be_io_error_kind_panic() {
+    let actual = std::io::Error::other("boom");
+    expect(&actual).to(be_io_error_kind(std::io::ErrorKind::NotFound));
+}
+
+#[test]
+fn test_eventually_should_be_file() {
+    let dir = std::env::temp_dir().join(format!("ferrous-eventually-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("output.txt");
+
+    let writer = path.clone();
+    let handle = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        std::fs::write(&writer, "done").unwrap();
+    });
+
+    let probe = path.clone();
+    eventually(move || {
+        probe.clone()
+    }).should(have_contents(equal(&String::from("done"))));
+    expect(&path).to(be_file());
+    expect(&dir).to(contain_entries(&["output.txt"]));
+    handle.join().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+#[should_panic(expected="expected path \"/nonexistent/ferrous\" to exist, found nothing")]
+fn test_expect_to_exist_panic() {
+    expect(&"/nonexistent/ferrous").to(exist());
+}
+
+#[test]
+fn test_expect_to_equal_bytes() {
+    let actual = vec![0xdeu8, 0xad, 0xbe, 0xef];
+    expect(&actual).to(equal_bytes(&[0xde, 0xad, 0xbe, 0xef]));
+    expect(&actual).to(equal_hex("de ad be ef"));
+    expect(&actual).to(start_with_bytes(b"\xde\xad"));
+    expect(&[0xdeu8, 0xad, 0xbe, 0xef]).to(contain_bytes(&vec![0xbe, 0xef]));
+}
+
+#[test]
+#[should_panic(expected="expected bytes to be equal, found 1 differing bytes (expected length 4, actual length 4) at offsets [0x3]")]
+fn test_expect_to_equal_hex_panic() {
+    let actual = vec![0xdeu8, 0xad, 0xbe, 0xee];
+    expect(&actual).to(equal_hex("deadbeef"));
+}
+
+#[test]
+fn test_expect_to_match_json() {
+    let actual = String::from("{\"id\": 7, \"tags\": [\"a\", \"b\"]}");
+    expect(&actual).to(match_json("{\"tags\": [\"a\", \"b\"], \"id\": 7}"));
+    expect(&actual).to(have_json_path("/tags/1", match_json("\"b\"")));
+}
+
+#[test]
+#[should_panic(expected="expected JSON to match, found 1 differences:\n  at \"/id\": expected 8, found 7")]
+fn test_expect_to_match_json_panic() {
+    let actual = "{\"id\": 7}";
+    expect(&actual).to(match_json("{\"id\": 8}"));
+}
+
+#[derive(Debug)]
+struct Report {
+    title: &'static str,
+    rows: Vec<(&'static str, u32)>,
+}
+
+#[test]
+fn test_expect_to_match_snapshot() {
+    let report = Report { title: "scores", rows: vec![("alice", 3), ("bob", 5)] };
+    expect(&report).to(match_snapshot!());
+    expect(&report.rows).to(match_snapshot!());
+    expect(&report.title).to(match_snapshot!("report_title").using_display());
+}
+
+#[test]
+fn test_expect_to_match_golden_file() {
+    use std::{env, fs, process};
+
+    let rendered = String::from("scores\r\n  alice: 3\r\n  bob: 5\r\n");
+    expect(&rendered).to(match_golden_file("tests/golden/report.txt").normalizing_line_endings());
+
+    // Mismatched output is written to `report.txt.actual`, so keep the mismatch out of the source tree.
+    let dir = env::temp_dir().join(format!("ferrous-golden-test-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let golden = dir.join("report.txt");
+    fs::copy("tests/golden/report.txt", &golden).unwrap();
+    expect(&rendered).not_to(match_golden_file(&golden));
+    let written = dir.join("report.txt.actual").exists();
+    fs::remove_dir_all(&dir).unwrap();
+    expect(&written).to(be_false());
+}
+
+#[test]
+fn test_expect_durations_and_times() {
+    use std::time::{Duration, Instant, SystemTime};
+
+    let start = Instant::now();
+    let elapsed = start.elapsed();
+    expect(&elapsed).to(be_shorter_than(Duration::from_secs(5)));
+    expect(&Duration::from_millis(1050)).to(be_within(Duration::from_millis(100)).of(Duration::from_secs(1)));
+    expect(&Instant::now()).to(be_after(start));
+    expect(&start).not_to(be_after(Instant::now() + Duration::from_secs(1)));
+    expect(&SystemTime::now()).to(be_within(Duration::from_secs(1)).of_now());
+}
+
+#[test]
+#[should_panic(expected="expected duration 2m 5s to be shorter than 1m")]
+fn test_expect_to_be_shorter_than_panic() {
+    expect(&std::time::Duration::from_secs(125)).to(be_shorter_than(std::time::Duration::from_secs(60)));
+}
+
+fn equal_greeting() -> impl Matcher<String> {
+    equal_value(format!("hello, {}", "world"))
+}
+
+#[test]
+fn test_expect_to_equal_value() {
+    let greeting = String::from("hello, world");
+    expect(&greeting).to(equal_value("hello, world"));
+    expect(&greeting).to(equal_greeting());
+    expect(&vec![1, 2, 3]).to(equal_value(&[1, 2, 3][..]));
+    expect(&7u8).to(equal_value(7));
+    expect(&Some(TestEnum::Pass)).not_to(equal_value(None));
+}
+
+#[test]
+fn test_expect_to_equal_deref() {
+    expect(&Box::new(5)).to(equal_deref(5));
+    expect(&Box::new(5)).not_to(equal_deref(6));
+    expect(&std::rc::Rc::new(String::from("abc"))).to(equal_deref("abc"));
+}
+
+#[test]
+#[should_panic(expected="expected 6 to equal 5")]
+fn test_expect_to_equal_deref_panic() {
+    expect(&Box::new(5)).to(equal_deref(6));
+}
+
+#[test]
+#[should_panic(expected="expected \"hello\" to equal \"world\"")]
+fn test_expect_to_equal_value_panic() {
+    expect(&String::from("world")).to(equal_value("hello"));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Record {
+    id: u64,
+    name: String,
+    score: f64,
+}
+
+#[test]
+fn test_expect_to_equal_by() {
+    let expected = Record { id: 1, name: String::from("alice"), score: 0.5 };
+    let actual = Record { id: 99, name: String::from("alice"), score: 0.5000001 };
+    expect(&actual).to(equal_by(&expected, |a: &Record, e| {
+        a.name == e.name && (a.score - e.score).abs() < 1e-3
+    }));
+    expect(&actual).to(equal_by_key(&expected, |r: &Record| r.name.clone()));
+    expect(&actual).not_to(equal_by_key(&expected, |r: &Record| r.id));
+    expect(&actual).not_to(equal_ignoring_fields!(&expected, Record { id }));
+    expect(&actual).to(equal_ignoring_fields!(&expected, Record { id, score }));
+}
+
+#[test]
+#[should_panic(expected="(compared by key: expected key 1, actual key 2)")]
+fn test_expect_to_equal_by_key_panic() {
+    let expected = Record { id: 1, name: String::from("alice"), score: 0.5 };
+    let actual = Record { id: 2, name: String::from("alice"), score: 0.5 };
+    expect(&actual).to(equal_by_key(&expected, |r: &Record| r.id));
+}
+
+#[test]
+fn test_expect_to_be_in_range_and_one_of() {
+    expect(&0.25).to(be_in_range(0.0..=1.0));
+    expect(&10).not_to(be_in_range(..10));
+    expect(&"HEAD").to(be_one_of(&["GET", "HEAD"]));
+    expect(&404).to(be_one_of_matching(vec![be_in_range(200..300), be_in_range(400..500)]));
+}
+
+#[test]
+#[should_panic(expected="expected 10 to be in range [0, 10), but it is above the upper bound 10")]
+fn test_expect_to_be_in_range_panic() {
+    expect(&10).to(be_in_range(0..10));
+}
+
+#[test]
+#[should_panic(expected="expected \"PUT\" to be one of [\"GET\", \"HEAD\"]")]
+fn test_expect_to_be_one_of_panic() {
+    expect(&"PUT").to(be_one_of(&["GET", "HEAD"]));
+}
+
+#[test]
+fn test_expect_numeric_properties() {
+    expect(&42u32).to(be_positive());
+    expect(&42u32).to(be_even());
+    expect(&42u32).to(be_multiple_of(7));
+    expect(&-3i8).to(be_negative());
+    expect(&-3i8).to(be_odd());
+    expect(&0.0).to(be_zero());
+    expect(&0.5f32).to(be_power_of_two());
+    expect(&f64::INFINITY).to(be_infinite());
+    expect(&f64::NAN).to(be_nan());
+    expect(&1e300).to(be_finite());
+    expect(&1e300).not_to(be_nan());
+}
+
+#[test]
+#[should_panic(expected="expected a non-negative number, found -1")]
+fn test_expect_not_to_be_negative_panic() {
+    expect(&-1).not_to(be_negative());
+}
+
+#[test]
+fn test_expect_to_be_close_to_all() {
+    let actual: Vec<f64> = (1..4).map(|i| f64::from(i) / 3.0 * 3.0).collect();
+    expect(&actual).to(be_close_to_all(&[1.0, 2.0, 3.0], 1e-9));
+
+    let matrix = vec![vec![0.1 + 0.2, 1.0], vec![2.0, 3.0]];
+    expect(&matrix).to(be_close_to_all(&vec![vec![0.3, 1.0], vec![2.0, 3.0]], 1e-9));
+    expect(&matrix).not_to(be_close_to_all(&vec![vec![0.3, 1.0]], 1e-9));
+}
+
+#[test]
+#[should_panic(expected="found 1 mismatches out of 2 (worst absolute error 0.5 at [1][0], worst relative error 0.25 at [1][0])")]
+fn test_expect_to_be_close_to_all_panic() {
+    let matrix = vec![vec![1.0], vec![2.5]];
+    expect(&matrix).to(be_close_to_all(&vec![vec![1.0], vec![2.0]], 0.1));
+}
+
+#[test]
+fn test_expect_sample_statistics() {
+    let latencies = vec![12, 15, 11, 14, 13, 12, 16, 15, 14, 18];
+    expect(&latencies).to(have_mean_close_to(14.0, 0.5));
+    expect(&latencies).to(have_std_dev_below(3.0));
+    expect(&latencies).to(have_percentile(90.0, be_in_range(15.0..=18.0)));
+    expect(&latencies).not_to(have_percentile(50.0, be_in_range(..10.0)));
+}
+
+#[test]
+fn test_expect_to_be_uniformly_distributed_over() {
+    let mut state = 12345u64;
+    let rolls: Vec<u64> = (0..600).map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % 6
+    }).collect();
+    expect(&rolls).to(be_uniformly_distributed_over(6, 0.01));
+    expect(&vec![0u8; 60]).not_to(be_uniformly_distributed_over(6, 0.01));
+}
+
+#[test]
+#[should_panic(expected="expected sample mean to be within 0.1 of 2.0, found mean 2.5 (n = 2)")]
+fn test_expect_to_have_mean_close_to_panic() {
+    expect(&[2.0, 3.0]).to(have_mean_close_to(2.0, 0.1));
+}
+
+#[test]
+#[should_panic(expected="but chi-square statistic 10.8000 with 3 degrees of freedom has p-value 1.2858e-2")]
+fn test_expect_to_be_uniformly_distributed_over_panic() {
+    expect(&vec![0, 0, 0, 0, 0, 0, 0, 1, 2, 3]).to(be_uniformly_distributed_over(4, 0.05));
+}
+
+#[test]
+fn test_expect_to_have_display_and_debug() {
+    let actual: Result<u8, _> = "300".parse::<u8>();
+    let err = actual.unwrap_err();
+    expect(&err).to(have_display(equal_value("number too large to fit in target type")));
+    expect(&err).to(have_debug(equal_value("ParseIntError { kind: PosOverflow }")));
+    expect(&err).to(have_pretty_debug(equal_value("ParseIntError {\n    kind: PosOverflow,\n}")));
+    expect(&TestEnum::Fail).not_to(have_debug(equal_value("Pass")));
+}
+
+#[test]
+#[should_panic(expected="expected Display output \"1.5s\" to match: expected \"1.50s\" to equal \"1.5s\"")]
+fn test_expect_to_have_display_panic() {
+    expect(&Seconds(1.5)).to(have_display(equal_value("1.50s")));
+}
+
+#[test]
+fn test_expect_to_round_trip() {
+    expect(&std::net::Ipv4Addr::new(10, 0, 0, 1)).to(round_trip_via_string());
+    expect(&f64::NAN).not_to(round_trip_via_string());
+    expect(&0xcafe_u16).to(round_trip_via(|x: &u16| format!("{:x}", x),
+                                          |s: &String| u16::from_str_radix(s, 16)));
+    expect(&vec![TestEnum::Pass, TestEnum::Fail]).to(clone_equal());
+}
+
+#[test]
+#[should_panic(expected="expected 300 to round-trip via [44], but it decoded as 44")]
+fn test_expect_to_round_trip_via_panic() {
+    expect(&300u16).to(round_trip_via(|x: &u16| vec![*x as u8],
+                                      |bytes: &Vec<u8>| -> Result<u16, ()> { Ok(u16::from(bytes[0])) }));
+}
+
+#[test]
+fn test_expect_trait_laws() {
+    let samples = vec!["", "a", "b", "ab", "a"];
+    expect(&samples).to(have_lawful_eq());
+    expect(&samples).to(have_hash_consistent_with_eq());
+    expect(&samples).to(have_lawful_ord());
+    expect(&vec![0.0, f64::NAN]).not_to(have_lawful_eq());
+}
+
+#[test]
+#[should_panic(expected="expected Eq to be lawful over 2 samples, but Eq is not reflexive: a = NaN, where a != a")]
+fn test_expect_to_have_lawful_eq_panic() {
+    expect(&vec![1.5, f64::NAN]).to(have_lawful_eq());
+}